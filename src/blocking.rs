@@ -0,0 +1,38 @@
+//! # Blocking
+//!
+//! Lets the requests be used without writing any async code.
+//!
+//! This module is only available with the `blocking` feature.
+//!
+//! Every request has an async `send` function, with the `blocking` feature every request also gets a `run` function.
+//! `run` sends the request through the same code as `send`,
+//! but waits for it on one runtime that is shared by the whole crate, instead of starting a new runtime each time.
+//!
+//! **NOTE:** `run` will panic if it is called from inside an async context, use `send` there instead.
+//!
+//! # Example:
+//!
+//! ```rust,no_run
+//! use speedrunapi::{Client, UserData};
+//! let client = Client::new();
+//! let result = UserData::new("fishin_rod").run(&client);
+//! println!("{:?}", result);
+//! ```
+
+use std::future::Future;
+use std::sync::OnceLock;
+use tokio::runtime::Runtime;
+
+static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+
+/// Waits for a future on the crates shared runtime, creating the runtime the first time it is needed
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output{
+    RUNTIME.get_or_init(|| {
+        tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("speedrunapi-blocking")
+            .enable_all()
+            .build()
+            .expect("Cannot create the blocking runtime")
+    }).block_on(future)
+}
@@ -0,0 +1,232 @@
+//! Caching
+//!
+//! Bots often look up the same games, users and metadata over and over.
+//! A client built with a [`Cache`] keeps the responses it gets in memory and answers the same request from there,
+//! until the response is older than the time to live of its kind of resource.
+//!
+//! Responses are stored by their path and query, with the query sorted,
+//! so the same filters set in a different order are one entry.
+//! Only successful responses are stored.
+//!
+//! # Example:
+//!
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), speedrunapi::Error> {
+//! use std::time::Duration;
+//! use speedrunapi::{Cache, Client, GameData};
+//! use speedrunapi::cache::Resource;
+//! let cache = Cache::new(Duration::from_secs(5 * 60))
+//!     .ttl(Resource::Platforms, Duration::from_secs(24 * 60 * 60))
+//!     .ttl(Resource::Leaderboards, Duration::from_secs(60));
+//! let client = Client::builder().cache(cache).build()?;
+//! let game = GameData::new("mc").send(&client).await?;
+//! // This is answered from the cache
+//! let game = GameData::new("mc").send(&client).await?;
+//! // This makes the next request for the game go to speedrun.com again
+//! client.cache().unwrap().invalidate("/games/mc");
+//! # Ok(())
+//! # }
+//! ```
+//! This will create a client that keeps platforms for a day, leaderboards for a minute and everything else for five minutes.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// The kinds of resources on speedrun.com, which each have their own time to live in a [`Cache`]
+///
+/// The kind of a request is the resource it returns, so the records of a game are leaderboards
+/// and the personal bests of a user are runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Resource{
+    Games,
+    Users,
+    Guests,
+    Runs,
+    Leaderboards,
+    Categories,
+    Levels,
+    Variables,
+    Series,
+    GameTypes,
+    Platforms,
+    Regions,
+    Genres,
+    Engines,
+    Developers,
+    Publishers,
+}
+
+impl Resource{
+
+    /// Returns the kind of resource a path returns, from the list it is for
+    ///
+    /// The path is split into the resource, its id and the list of the resource, such as `/games/mc/categories`.
+    fn of(path: &str) -> Option<Resource>{
+        let (path, _) = normalize(path);
+        let parts: Vec<&str> = path.trim_start_matches('/').split('/').collect();
+        parts.get(2).and_then(|list| Resource::named(list)).or_else(|| Resource::named(parts[0]))
+    }
+
+    /// Returns the kind of resource a list in a path is for
+    fn named(list: &str) -> Option<Resource>{
+        let resource = match list{
+            "games" | "derived-games" => Resource::Games,
+            "users" => Resource::Users,
+            "guests" => Resource::Guests,
+            "runs" | "personal-bests" => Resource::Runs,
+            "leaderboards" | "records" => Resource::Leaderboards,
+            "categories" => Resource::Categories,
+            "levels" => Resource::Levels,
+            "variables" => Resource::Variables,
+            "series" => Resource::Series,
+            "gametypes" => Resource::GameTypes,
+            "platforms" => Resource::Platforms,
+            "regions" => Resource::Regions,
+            "genres" => Resource::Genres,
+            "engines" => Resource::Engines,
+            "developers" => Resource::Developers,
+            "publishers" => Resource::Publishers,
+            _ => return None,
+        };
+        Some(resource)
+    }
+}
+
+/// An in memory cache of responses, shared by every clone of the client it is built into
+#[derive(Debug)]
+pub struct Cache{
+    default_ttl: Duration,
+    ttls: HashMap<Resource, Duration>,
+    entries: Mutex<HashMap<String, Entry>>,
+    /// The base url of the client the cache is built into
+    base_url: Option<String>,
+}
+
+#[derive(Debug)]
+struct Entry{
+    resource: Option<Resource>,
+    body: String,
+    expires: Instant,
+}
+
+impl Cache{
+
+    /// Creates an empty cache that keeps every response for the time to live
+    ///
+    /// # Arguments:
+    ///
+    /// `default_ttl: Duration` - How long responses are kept unless their kind of resource has its own time
+    pub fn new(default_ttl: Duration) -> Cache{
+        Cache{
+            default_ttl,
+            ttls: HashMap::new(),
+            entries: Mutex::new(HashMap::new()),
+            base_url: None,
+        }
+    }
+
+    /// Sets how long the responses for a kind of resource are kept, a time of zero turns caching off for it
+    ///
+    /// # Arguments:
+    ///
+    /// `resource: Resource` - The kind of resource
+    ///
+    /// `ttl: Duration` - How long its responses are kept
+    pub fn ttl(mut self, resource: Resource, ttl: Duration) -> Self{
+        self.ttls.insert(resource, ttl);
+        self
+    }
+
+    /// Removes the responses for a path whatever their query, such as `/games/mc`
+    ///
+    /// Paths are the part of the url after the base url of the client,
+    /// full urls with the base url of the client or of speedrun.com work too.
+    pub fn invalidate(&self, path: &str){
+        let path = self.base_url.as_deref().and_then(|base_url| path.strip_prefix(base_url))
+            .or_else(|| path.strip_prefix(crate::client::DEFAULT_BASE_URL))
+            .unwrap_or(path);
+        let (path, _) = normalize(path);
+        self.entries().retain(|key, _| key.split('?').next() != Some(path.as_str()));
+    }
+
+    /// Removes the responses for every resource of a kind
+    pub fn invalidate_resource(&self, resource: Resource){
+        self.entries().retain(|_, entry| entry.resource != Some(resource));
+    }
+
+    /// Removes every response
+    pub fn clear(&self){
+        self.entries().clear();
+    }
+
+    /// Returns how many responses are stored, including the ones that are too old but have not been removed yet
+    pub fn len(&self) -> usize{
+        self.entries().len()
+    }
+
+    /// Returns true if no responses are stored
+    pub fn is_empty(&self) -> bool{
+        self.entries().is_empty()
+    }
+
+    /// Sets the base url of the client the cache is built into, which is stripped from the urls passed to `invalidate`
+    pub(crate) fn base_url(mut self, base_url: &str) -> Cache{
+        self.base_url = Some(base_url.to_string());
+        self
+    }
+
+    /// Returns the stored response for the path, if there is one that is not too old
+    pub(crate) fn get(&self, path: &str) -> Option<String>{
+        let key = key(path);
+        let mut entries = self.entries();
+        match entries.get(&key){
+            Some(entry) if entry.expires > Instant::now() => Some(entry.body.clone()),
+            Some(_) => {
+                entries.remove(&key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Stores the response for the path, removing the responses that are too old
+    pub(crate) fn insert(&self, path: &str, body: &str){
+        let resource = Resource::of(path);
+        let ttl = resource.and_then(|resource| self.ttls.get(&resource)).copied().unwrap_or(self.default_ttl);
+        if ttl.is_zero(){
+            return;
+        }
+        let now = Instant::now();
+        let mut entries = self.entries();
+        entries.retain(|_, entry| entry.expires > now);
+        entries.insert(key(path), Entry{
+            resource,
+            body: body.to_string(),
+            expires: now + ttl,
+        });
+    }
+
+    fn entries(&self) -> std::sync::MutexGuard<'_, HashMap<String, Entry>>{
+        self.entries.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+/// Returns the key a path is stored under, the path with its query sorted
+fn key(path: &str) -> String{
+    let (path, mut query) = normalize(path);
+    query.sort();
+    if query.is_empty(){
+        return path;
+    }
+    format!("{}?{}", path, query.join("&"))
+}
+
+/// Splits a path into the path without a trailing `/` and the pairs of its query
+fn normalize(path: &str) -> (String, Vec<&str>){
+    let (path, query) = path.split_once('?').unwrap_or((path, ""));
+    let path = format!("/{}", path.trim_matches('/'));
+    let query = query.split('&').filter(|pair| !pair.is_empty()).collect();
+    (path, query)
+}
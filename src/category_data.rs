@@ -0,0 +1,185 @@
+//! Categories
+//! 
+//! This module provides data about categories on speedrun.com
+//! 
+//! A category is a set of rules runs of a game are done with, such as Any% or 100%.
+//! Categories are either for the full game or for levels, and can be for one player or for several.
+//! 
+//! # Arguments:
+//! 
+//! Create the request with CategoryData::new("category_id") and call .send(&client).await with a [`Client`](crate::Client).
+//! The variables and records of the category are fetched with their own requests.
+//! 
+//! # Examples:
+//! 
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), speedrunapi::Error> {
+//! use speedrunapi::{Client, CategoryData};
+//! let client = Client::new();
+//! let result = CategoryData::new("mkeyl926").send(&client).await?;
+//! println!("{} is for {} player(s)", result.name, result.max_players());
+//! # Ok(())
+//! # }
+//! ```
+//! This will print the name of the category and how many players it allows.
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), speedrunapi::Error> {
+//! use speedrunapi::{Client, CategoryData};
+//! let client = Client::new();
+//! let result = CategoryData::new("mkeyl926").records().top(1).send(&client).await?;
+//! println!("{:?}", result);
+//! # Ok(())
+//! # }
+//! ```
+//! This will fetch the world record of the category.
+
+use crate::types::Data;
+use crate::query::Query;
+use crate::embed::{CategoryEmbed, LeaderboardEmbed};
+use crate::types::{Category, CategoryType, Players, Variable, Leaderboard};
+use crate::{Client, Error};
+
+#[derive(Debug)]
+pub struct CategoryData{
+    pub id: String,
+    query: Query,
+}
+
+Sub_Resource!("A request for the variables of a category, created with [`CategoryData::variables`]", CategoryVariables, "categories", "variables", Variable);
+Paged_Sub_Resource!("A request for the records of a category, created with [`CategoryData::records`]", CategoryRecords, "categories", "records", Leaderboard);
+
+impl Category{
+
+    /// Returns true if the category is for levels instead of the full game
+    /// 
+    /// ## Example:
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, CategoryData};
+    /// let client = Client::new();
+    /// let result = CategoryData::new("mkeyl926").send(&client).await?;
+    /// assert!(!result.is_per_level());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_per_level(&self) -> bool{
+        self.category_type == CategoryType::PerLevel
+    }
+
+    /// Returns the most players a run of the category can have
+    pub fn max_players(&self) -> u32{
+        match self.players{
+            Players::Exactly(players) | Players::UpTo(players) => players,
+        }
+    }
+
+    /// Returns true if runs of the category are done by one player
+    pub fn is_solo(&self) -> bool{
+        self.max_players() == 1
+    }
+}
+
+impl CategoryData{
+
+    /// Creates a new CategoryData object
+    /// 
+    /// # Arguments:
+    /// 
+    /// `id: &str` - The id of the category
+    /// 
+    /// # Examples:
+    /// ```rust
+    /// use speedrunapi::CategoryData;
+    /// let result = CategoryData::new("mkeyl926");
+    /// println!("{:?}", result);
+    /// ```
+    pub fn new(id: &str) -> CategoryData{
+        CategoryData{
+            id: id.to_string(),
+            query: Query::default(),
+        }
+    }
+
+    /// Creates a request for the variables of the category
+    /// 
+    /// # Example:
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, CategoryData};
+    /// let client = Client::new();
+    /// let result = CategoryData::new("mkeyl926").variables().send(&client).await?;
+    /// println!("{:?}", result);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn variables(&self) -> CategoryVariables{
+        CategoryVariables::new(&self.id)
+    }
+
+    /// Creates a request for the records of the category
+    /// 
+    /// For a full game category this is one leaderboard, for a level category there is one for every level.
+    /// 
+    /// # Example:
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, CategoryData};
+    /// let client = Client::new();
+    /// let result = CategoryData::new("mkeyl926").records().send(&client).await?;
+    /// println!("{:?}", result);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn records(&self) -> CategoryRecords{
+        CategoryRecords::new(&self.id)
+    }
+
+    /// Runs the request to the speedrun.com API
+    /// 
+    /// # Returns:
+    /// 
+    /// The category, or the [`Error`] if the request failed or the category could not be found
+    /// 
+    /// # Example:
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, CategoryData};
+    /// let client = Client::new();
+    /// let result = CategoryData::new("mkeyl926").send(&client).await?;
+    /// println!("{:?}", result);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send(&self, client: &Client) -> Result<Category, Error>{
+        let path = self.query.path(&format!("/categories/{}", self.id));
+        let response = client.get_json::<Data<Category>>(&path).await?;
+        Ok(response.data)
+    }
+
+    Embed_Function!(CategoryEmbed);
+
+    Blocking_Function!(Result<Category, Error>);
+}
+
+impl CategoryRecords{
+
+    /// Only returns the runs in the top places of each leaderboard
+    pub fn top(mut self, top: u32) -> Self{
+        self.query.set("top", &top.to_string());
+        self
+    }
+
+    /// Leaves out the leaderboards that have no runs if true
+    pub fn skip_empty(mut self, skip_empty: bool) -> Self{
+        self.query.set_bool("skip-empty", skip_empty);
+        self
+    }
+
+    Embed_Function!(LeaderboardEmbed);
+}
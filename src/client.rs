@@ -0,0 +1,372 @@
+//! # Client
+//!
+//! Handles the connection to the speedrun.com API.
+//!
+//! What is the client for?
+//! Every request made by the crate goes through a client.
+//! The client owns one connection pool, so creating it once and passing it to every request
+//! lets lots of lookups reuse the same connections instead of opening new ones each time.
+//!
+//! The client does not start its own runtime, so it can be used from inside any async context.
+//!
+//! # Examples:
+//!
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), speedrunapi::Error> {
+//! use speedrunapi::{Client, UserData};
+//! let client = Client::new();
+//! let result = UserData::new("fishin_rod").send(&client).await?;
+//! println!("{:?}", result);
+//! # Ok(())
+//! # }
+//! ```
+//! This will create a client with the default settings and use it to fetch a user.
+//! ```rust
+//! use std::time::Duration;
+//! use speedrunapi::Client;
+//! let client = Client::builder()
+//!     .user_agent("my-bot/1.0")
+//!     .timeout(Duration::from_secs(10))
+//!     .build()
+//!     .unwrap();
+//! ```
+//! This will create a client with a custom user agent and timeout.
+//! ```rust
+//! use speedrunapi::Client;
+//! let client = Client::builder().base_url("http://localhost:8080/api/v1").build().unwrap();
+//! ```
+//! This will create a client that sends its requests to a local server instead of speedrun.com.
+//!
+//! # Rate limiting:
+//!
+//! speedrun.com allows 100 requests a minute, so the client waits before sending a request that would go over that.
+//! The limit is shared by every clone of the client, so requests sent from different tasks are counted together.
+//! ```rust
+//! use std::time::Duration;
+//! use speedrunapi::Client;
+//! let client = Client::builder().rate_limit(50, Duration::from_secs(60)).build().unwrap();
+//! ```
+//! This will create a client that sends at most 50 requests a minute.
+//!
+//! If speedrun.com rate limits a request anyway, every request waits for the delay it asked for,
+//! and the request returns [`Error::RateLimited`] with that delay.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::de::DeserializeOwned;
+
+use crate::{Cache, Error, RetryPolicy};
+use crate::rate_limit::RateLimiter;
+use crate::retry::{self, Failure};
+
+/// The url of the speedrun.com API, used unless another one is set on the builder
+pub const DEFAULT_BASE_URL: &str = "https://www.speedrun.com/api/v1";
+
+/// The user agent sent with every request unless one is set on the builder
+const DEFAULT_USER_AGENT: &str = concat!("speedrunapi-rust/", env!("CARGO_PKG_VERSION"));
+
+/// How many requests speedrun.com allows in [`DEFAULT_RATE_PERIOD`]
+pub const DEFAULT_RATE_LIMIT: u32 = 100;
+
+/// The period speedrun.com counts its rate limit over
+pub const DEFAULT_RATE_PERIOD: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone)]
+pub struct Client{
+    http: reqwest::Client,
+    base_url: String,
+    limiter: Option<Arc<RateLimiter>>,
+    retry: RetryPolicy,
+    cache: Option<Arc<Cache>>,
+}
+
+#[derive(Debug)]
+pub struct ClientBuilder{
+    base_url: String,
+    user_agent: String,
+    timeout: Option<Duration>,
+    rate_limit: Option<(u32, Duration)>,
+    retry: RetryPolicy,
+    cache: Option<Cache>,
+}
+
+impl Client{
+
+    /// Creates a new Client with the default settings
+    ///
+    /// ## Returns:
+    ///
+    /// A new Client
+    ///
+    /// ## Panics!
+    ///
+    /// This function will panic if the underlying HTTP client cannot be created,
+    /// use [`Client::builder`] if you want to handle that error.
+    ///
+    /// ## Example:
+    /// ```rust
+    /// use speedrunapi::Client;
+    /// let client = Client::new();
+    /// ```
+    pub fn new() -> Client{
+        Client::builder().build().expect("Cannot create the HTTP client")
+    }
+
+    /// Creates a new ClientBuilder to configure a Client
+    ///
+    /// ## Returns:
+    ///
+    /// A new ClientBuilder with the default settings
+    ///
+    /// ## Example:
+    /// ```rust
+    /// use speedrunapi::Client;
+    /// let client = Client::builder().user_agent("my-bot/1.0").build().unwrap();
+    /// ```
+    pub fn builder() -> ClientBuilder{
+        ClientBuilder::new()
+    }
+
+    /// Returns the base url every request is sent to
+    ///
+    /// ## Example:
+    /// ```rust
+    /// use speedrunapi::Client;
+    /// let client = Client::new();
+    /// assert_eq!(client.base_url(), "https://www.speedrun.com/api/v1");
+    /// ```
+    pub fn base_url(&self) -> &str{
+        &self.base_url
+    }
+
+    /// Returns the cache of the client, or None if it was built without one
+    ///
+    /// See the [cache](crate::cache) module for more information.
+    pub fn cache(&self) -> Option<&Cache>{
+        self.cache.as_deref()
+    }
+
+    /// Turns a link sent by speedrun.com into a path for the client
+    ///
+    /// Links to speedrun.com are sent to the base url instead, so lists from a stand-in server or proxy stay on it.
+    /// Links to anywhere else are returned as they are.
+    pub(crate) fn relative<'a>(&self, link: &'a str) -> &'a str{
+        link.strip_prefix(self.base_url.as_str())
+            .or_else(|| link.strip_prefix(DEFAULT_BASE_URL))
+            .unwrap_or(link)
+    }
+
+    /// Sends a GET request for the path through the clients connection pool
+    ///
+    /// The path is added to the end of the base url, so it should start with a `/`,
+    /// unless it is a full url, which is requested as it is
+    ///
+    /// Any response without a success status is turned into an [`Error`],
+    /// after the request has been retried as often as the clients [`RetryPolicy`] allows
+    ///
+    /// If the client has a cache the response is taken from it when it can be, and stored in it otherwise
+    pub(crate) async fn get(&self, path: &str) -> Result<String, Error>{
+        let key = self.relative(path);
+        if let Some(body) = self.cache.as_ref().and_then(|cache| cache.get(key)){
+            return Ok(body);
+        }
+        let url = if path.starts_with("http://") || path.starts_with("https://"){
+            path.to_string()
+        }
+        else{
+            format!("{}{}", self.base_url, path)
+        };
+        let mut attempt = 1;
+        loop{
+            let failure = match self.attempt(&url).await{
+                Ok(body) => {
+                    if let Some(cache) = &self.cache{
+                        cache.insert(key, &body);
+                    }
+                    return Ok(body);
+                }
+                Err(failure) => failure,
+            };
+            match self.retry.delay(attempt, &failure){
+                Some(delay) => tokio::time::sleep(delay).await,
+                None => return Err(failure.error),
+            }
+            attempt += 1;
+        }
+    }
+
+    /// Sends the request once
+    ///
+    /// Waits for the rate limit first, and makes every request wait if speedrun.com rate limits this one
+    async fn attempt(&self, url: &str) -> Result<String, Failure>{
+        if let Some(limiter) = &self.limiter{
+            limiter.acquire().await;
+        }
+        let response = self.http.get(url).send().await.map_err(Error::from)?;
+        let status = response.status();
+        let retry_after = response.headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(retry::retry_after);
+        let body = response.text().await.map_err(Error::from)?;
+        if !status.is_success(){
+            let mut error = Error::from_status(status, retry_after, &body);
+            if let (Error::RateLimited{ retry_after }, Some(limiter)) = (&mut error, &self.limiter){
+                let delay = retry_after.unwrap_or(limiter.period());
+                limiter.pause(delay);
+                *retry_after = Some(delay);
+            }
+            return Err(Failure{
+                error,
+                status: Some(status.as_u16()),
+                retry_after,
+            });
+        }
+        Ok(body)
+    }
+
+    /// Sends a GET request for the path and decodes the JSON response
+    ///
+    /// If the response cannot be decoded the error contains the path to the value that failed
+    pub(crate) async fn get_json<T: DeserializeOwned>(&self, path: &str) -> Result<T, Error>{
+        let body = self.get(path).await?;
+        let deserializer = &mut serde_json::Deserializer::from_str(&body);
+        serde_path_to_error::deserialize(deserializer).map_err(|err| Error::Decode{
+            path: err.path().to_string(),
+            source: err.into_inner(),
+        })
+    }
+}
+
+impl Default for Client{
+    fn default() -> Self{
+        Client::new()
+    }
+}
+
+impl ClientBuilder{
+
+    /// Creates a new ClientBuilder with the default settings
+    pub fn new() -> ClientBuilder{
+        ClientBuilder{
+            base_url: DEFAULT_BASE_URL.to_string(),
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            timeout: None,
+            rate_limit: Some((DEFAULT_RATE_LIMIT, DEFAULT_RATE_PERIOD)),
+            retry: RetryPolicy::none(),
+            cache: None,
+        }
+    }
+
+    /// Sets the url every request is sent to
+    ///
+    /// By default requests go to speedrun.com, this can be changed to send them to
+    /// a local stand-in server for testing or to a caching proxy.
+    ///
+    /// ## Arguments:
+    ///
+    /// `base_url: &str` - The url to put before every path, such as "http://localhost:8080/api/v1"
+    ///
+    /// ## Example:
+    /// ```rust
+    /// use speedrunapi::Client;
+    /// let client = Client::builder().base_url("http://localhost:8080/api/v1/").build().unwrap();
+    /// assert_eq!(client.base_url(), "http://localhost:8080/api/v1");
+    /// ```
+    pub fn base_url(mut self, base_url: &str) -> Self{
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Sets the user agent sent with every request
+    ///
+    /// ## Arguments:
+    ///
+    /// `user_agent: &str` - The user agent, speedrun.com asks that it identifies your application
+    pub fn user_agent(mut self, user_agent: &str) -> Self{
+        self.user_agent = user_agent.to_string();
+        self
+    }
+
+    /// Sets the timeout for every request
+    ///
+    /// ## Arguments:
+    ///
+    /// `timeout: Duration` - How long to wait for a response before giving up
+    pub fn timeout(mut self, timeout: Duration) -> Self{
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets how many requests the client sends in a period, by default the 100 a minute speedrun.com allows
+    ///
+    /// The requests are spread out so the limit is never gone over, but a burst of up to `requests` is sent at once.
+    ///
+    /// ## Arguments:
+    ///
+    /// `requests: u32` - How many requests can be sent in the period, at least 1
+    ///
+    /// `period: Duration` - How long the requests are counted over
+    pub fn rate_limit(mut self, requests: u32, period: Duration) -> Self{
+        self.rate_limit = Some((requests, period));
+        self
+    }
+
+    /// Turns the rate limit off, for servers that have no limit such as a local stand-in
+    pub fn no_rate_limit(mut self) -> Self{
+        self.rate_limit = None;
+        self
+    }
+
+    /// Sets when failed requests are sent again, by default they are not
+    ///
+    /// See the [retry](crate::retry) module for more information.
+    ///
+    /// ## Arguments:
+    ///
+    /// `retry: RetryPolicy` - How often and after how long requests are retried
+    pub fn retry(mut self, retry: RetryPolicy) -> Self{
+        self.retry = retry;
+        self
+    }
+
+    /// Keeps the responses in a cache and answers requests from it, by default nothing is cached
+    ///
+    /// See the [cache](crate::cache) module for more information.
+    ///
+    /// ## Arguments:
+    ///
+    /// `cache: Cache` - The cache, with how long each kind of resource is kept
+    pub fn cache(mut self, cache: Cache) -> Self{
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Builds the Client
+    ///
+    /// ## Returns:
+    ///
+    /// The Client, or the error if the underlying HTTP client could not be created
+    pub fn build(self) -> Result<Client, Error>{
+        let mut http = reqwest::Client::builder().user_agent(self.user_agent);
+        if let Some(timeout) = self.timeout{
+            http = http.timeout(timeout);
+        }
+        let cache = self.cache.map(|cache| Arc::new(cache.base_url(&self.base_url)));
+        Ok(Client{
+            http: http.build()?,
+            base_url: self.base_url,
+            limiter: self.rate_limit.map(|(requests, period)| Arc::new(RateLimiter::new(requests, period))),
+            retry: self.retry,
+            cache,
+        })
+    }
+}
+
+impl Default for ClientBuilder{
+    fn default() -> Self{
+        ClientBuilder::new()
+    }
+}
@@ -0,0 +1,205 @@
+//! Embedding
+//!
+//! speedrun.com can embed other resources into the resource a request returns,
+//! such as the categories and levels into a game, so they don't need requests of their own.
+//!
+//! Every request that returns a resource that can embed others has an `embed` function,
+//! which takes the embeds of that resource and can be called more than once.
+//! The embedded resources are decoded into the `embedded` field of the model,
+//! every other field stays the same as it is without the embed.
+//!
+//! # Example:
+//!
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), speedrunapi::Error> {
+//! use speedrunapi::{Client, GameData};
+//! use speedrunapi::embed::{GameEmbed, CategoryEmbed};
+//! let client = Client::new();
+//! let game = GameData::new("mc")
+//!     .embed(GameEmbed::Categories.with(CategoryEmbed::Variables))
+//!     .embed(GameEmbed::Levels)
+//!     .send(&client)
+//!     .await?;
+//! for category in game.embedded.categories.unwrap_or_default(){
+//!     println!("{} has {} variables", category.name, category.embedded.variables.unwrap_or_default().len());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//! This fetches Minecraft: Java Edition with its categories, their variables and its levels in one request.
+//!
+//! Only the embeds of the embedded resource can be passed to `with`, and resources that nothing can be embedded into,
+//! such as platforms, have no `with` at all:
+//!
+//! ```rust,compile_fail
+//! use speedrunapi::embed::{GameEmbed, RunEmbed};
+//! let embed = GameEmbed::Categories.with(RunEmbed::Players);
+//! ```
+//!
+//! ```rust,compile_fail
+//! use speedrunapi::embed::{GameEmbed, CategoryEmbed};
+//! let embed = GameEmbed::Platforms.with(CategoryEmbed::Variables);
+//! ```
+
+use std::fmt;
+use std::marker::PhantomData;
+
+/// An embed of a resource into the resource `E`, `N` is what can be embedded into the embedded resource in turn
+///
+/// Resources that nothing can be embedded into have [`Leaf`] as `N`, so they have no `with`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Embed<E, N>{
+    key: &'static str,
+    resource: PhantomData<(E, N)>,
+}
+
+impl<E, N> Embed<E, N>{
+    const fn new(key: &'static str) -> Embed<E, N>{
+        Embed{
+            key,
+            resource: PhantomData,
+        }
+    }
+}
+
+impl<E, N: ResourceEmbeds> Embed<E, N>{
+
+    /// Embeds resources into the embedded resource, such as the variables into every embedded category
+    ///
+    /// # Arguments:
+    ///
+    /// `embed` - An embed of the resource that is embedded
+    pub fn with(self, embed: impl Into<Embedding<N>>) -> Embedding<E>{
+        Embedding{
+            path: format!("{}.{}", self.key, embed.into().path),
+            resource: PhantomData,
+        }
+    }
+}
+
+impl<E, N> From<Embed<E, N>> for Embedding<E>{
+    fn from(embed: Embed<E, N>) -> Embedding<E>{
+        Embedding{
+            path: embed.key.to_string(),
+            resource: PhantomData,
+        }
+    }
+}
+
+/// An embed of the resource `E`, with the embeds of the embedded resource if it has any
+///
+/// Created from an embed with `with`, or with `into` for an embed on its own.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Embedding<E>{
+    path: String,
+    resource: PhantomData<E>,
+}
+
+impl<E> fmt::Display for Embedding<E>{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result{
+        write!(f, "{}", self.path)
+    }
+}
+
+/// What can be embedded into a resource that nothing can be embedded into, such as a platform
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Leaf{}
+
+/// The embeds of a resource other resources can be embedded into, such as [`GameEmbed`]
+///
+/// This is only implemented by the embeds in this module.
+pub trait ResourceEmbeds: sealed::Sealed{}
+
+mod sealed{
+    pub trait Sealed{}
+}
+
+/// Returns the embeds of the embedded resource, or [`Leaf`] if nothing can be embedded into it
+macro_rules! Nested {
+    () => { Leaf };
+    ($nested:ident) => { $nested };
+}
+
+/// Generates the embeds of a resource, `with` names the embeds of the embedded resource if it has any
+macro_rules! Embeds {
+    ($doc:expr, $name:ident, $($(#[$meta:meta])* $variant:ident => $key:literal $(with $nested:ident)?),+ $(,)?) => {
+        #[doc = $doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct $name;
+
+        #[allow(non_upper_case_globals)]
+        impl $name{
+            $($(#[$meta])* pub const $variant: Embed<$name, Nested!($($nested)?)> = Embed::new($key);)+
+        }
+
+        impl sealed::Sealed for $name{}
+        impl ResourceEmbeds for $name{}
+    };
+}
+
+Embeds!("The resources that can be embedded into a game", GameEmbed,
+    Levels => "levels" with LevelEmbed,
+    Categories => "categories" with CategoryEmbed,
+    /// The moderators of the game, the roles of the moderators are not returned with them
+    /// so [`Game::moderators`](crate::Game::moderators) has None for every role
+    Moderators => "moderators",
+    GameTypes => "gametypes",
+    Platforms => "platforms",
+    Regions => "regions",
+    Genres => "genres",
+    Engines => "engines",
+    Developers => "developers",
+    Publishers => "publishers",
+    Variables => "variables",
+);
+
+Embeds!("The resources that can be embedded into a category", CategoryEmbed,
+    Game => "game" with GameEmbed,
+    Variables => "variables",
+);
+
+Embeds!("The resources that can be embedded into a level", LevelEmbed,
+    Categories => "categories" with CategoryEmbed,
+    Variables => "variables",
+);
+
+Embeds!("The resources that can be embedded into a run", RunEmbed,
+    Game => "game" with GameEmbed,
+    Category => "category" with CategoryEmbed,
+    /// The level of the run, nothing is embedded for full game runs
+    Level => "level" with LevelEmbed,
+    Players => "players",
+    Region => "region",
+    Platform => "platform",
+);
+
+Embeds!("The resources that can be embedded into a personal best, which are put next to its run", PersonalBestEmbed,
+    Game => "game" with GameEmbed,
+    Category => "category" with CategoryEmbed,
+    /// The level of the personal best, nothing is embedded for full game runs
+    Level => "level" with LevelEmbed,
+    Players => "players",
+    Region => "region",
+    Platform => "platform",
+);
+
+Embeds!("The resources that can be embedded into a leaderboard", LeaderboardEmbed,
+    Game => "game" with GameEmbed,
+    Category => "category" with CategoryEmbed,
+    /// The level of the leaderboard, nothing is embedded for full game leaderboards
+    Level => "level" with LevelEmbed,
+    /// The players of every run on the leaderboard
+    Players => "players",
+    /// Every region the leaderboard could be filtered by
+    Regions => "regions",
+    /// Every platform the leaderboard could be filtered by
+    Platforms => "platforms",
+    /// Every variable of the leaderboard
+    Variables => "variables",
+);
+
+Embeds!("The resources that can be embedded into a series", SeriesEmbed,
+    /// The moderators of the series, the roles of the moderators are not returned with them
+    Moderators => "moderators",
+);
@@ -0,0 +1,104 @@
+//! # Errors
+//!
+//! Every request in the crate returns the same error type when something goes wrong.
+//!
+//! The error implements `std::error::Error`, so it can be used with `?` alongside other errors.
+//!
+//! # Example:
+//!
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() {
+//! use speedrunapi::{Client, Error, GameTypeData};
+//! let client = Client::new();
+//! match GameTypeData::new("Fangame").send(&client).await{
+//!     Ok(gametype) => println!("{}", gametype.name()),
+//!     Err(Error::NotFound{ message }) => println!("{}", message),
+//!     Err(err) => println!("{}", err),
+//! }
+//! # }
+//! ```
+
+use std::fmt;
+use std::time::Duration;
+
+use serde::Deserialize;
+
+#[derive(Debug)]
+pub enum Error{
+    /// The thing that was asked for does not exist on speedrun.com
+    NotFound{
+        message: String,
+    },
+    /// Speedrun.com is getting too many requests and asked to slow down
+    ///
+    /// `retry_after` is the delay speedrun.com asked for, or the period of the clients rate limit if it did not say.
+    /// The client waits for the delay before sending any other request, see the [client](crate::client) module.
+    RateLimited{
+        retry_after: Option<Duration>,
+    },
+    /// Speedrun.com answered with an error status
+    Server{
+        status: reqwest::StatusCode,
+        message: String,
+    },
+    /// The response could not be turned into the crates data types
+    Decode{
+        path: String,
+        source: serde_json::Error,
+    },
+    /// The request could not be sent or the response could not be read
+    Transport(reqwest::Error),
+}
+
+/// The JSON body speedrun.com sends along with an error status
+#[derive(Deserialize)]
+struct ErrorBody{
+    message: String,
+}
+
+impl Error{
+
+    /// Creates the error for a response that did not have a success status
+    ///
+    /// Speedrun.com uses the status 420 when you are being rate limited.
+    pub(crate) fn from_status(status: reqwest::StatusCode, retry_after: Option<Duration>, body: &str) -> Error{
+        let message = serde_json::from_str::<ErrorBody>(body)
+            .map(|body| body.message)
+            .unwrap_or_else(|_| body.to_string());
+        match status.as_u16(){
+            404 => Error::NotFound{ message },
+            420 | 429 => Error::RateLimited{ retry_after },
+            _ => Error::Server{ status, message },
+        }
+    }
+}
+
+impl fmt::Display for Error{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result{
+        match self{
+            Error::NotFound{ message } => write!(f, "not found: {}", message),
+            Error::RateLimited{ retry_after: Some(delay) } => write!(f, "rate limited by speedrun.com, retry after {:?}", delay),
+            Error::RateLimited{ retry_after: None } => write!(f, "rate limited by speedrun.com"),
+            Error::Server{ status, message } => write!(f, "speedrun.com returned {}: {}", status, message),
+            Error::Decode{ path, source } => write!(f, "cannot decode the response at `{}`: {}", path, source),
+            Error::Transport(err) => write!(f, "cannot reach speedrun.com: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)>{
+        match self{
+            Error::Decode{ source, .. } => Some(source),
+            Error::Transport(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error{
+    fn from(err: reqwest::Error) -> Self{
+        Error::Transport(err)
+    }
+}
@@ -5,281 +5,498 @@
 //! What is a game?
 //! A game is where users can submit runs for different categories and leaderboards connected to the game.
 //! 
-
-use crate::types::GameData as Data;
-use crate::tl_time;
+//! # Sub resources:
+//! 
+//! The categories, levels, variables, derived games and records of a game are fetched with their own requests,
+//! which are created from a GameData object:
+//! 
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), speedrunapi::Error> {
+//! use speedrunapi::{Client, GameData};
+//! let client = Client::new();
+//! let result = GameData::new("mc").categories().send(&client).await?;
+//! for category in result{
+//!     println!("{}", category.name);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//! This will print the name of every category of Minecraft: Java Edition.
+//! 
+//! # Searching:
+//! 
+//! Games can be searched for and listed with [`GameData::list`]:
+//! 
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), speedrunapi::Error> {
+//! use speedrunapi::{Client, GameData};
+//! let client = Client::new();
+//! let result = GameData::list().name("minecraft").send(&client).await?;
+//! for game in result{
+//!     println!("{} ({})", game.name(), game.abbreviation);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//! This will print the games with a name like "minecraft", the closest matches first.
+
+use std::collections::BTreeMap;
+
+use crate::types::Data;
+use crate::types::{Game, BulkGame, Category, CategoryType, Level, Variable, Leaderboard, Board, ModeratorRole};
+use crate::translate;
+use crate::query::Query;
+use crate::embed::{GameEmbed, CategoryEmbed, LevelEmbed, LeaderboardEmbed};
+use crate::{Client, Error, LeaderboardData};
 
 #[derive(Debug)]
 pub struct GameData{
     pub name: String,
-    categories: bool,
-    levels: bool,
-    variables: bool,
-    derived_games: bool,
-    records: bool,
+    query: Query,
 }
 
-#[derive(Debug)]
-pub enum GameResult {
-    Game(Data),
-    None,
-    Error(GameError),
+Sub_Resource!("A request for the categories of a game, created with [`GameData::categories`]", GameCategories, "games", "categories", Category);
+Sub_Resource!("A request for the levels of a game, created with [`GameData::levels`]", GameLevels, "games", "levels", Level);
+Sub_Resource!("A request for the variables of a game, created with [`GameData::variables`]", GameVariables, "games", "variables", Variable);
+Paged_Sub_Resource!("A request for the games derived from a game, such as its romhacks, created with [`GameData::derived_games`]", DerivedGames, "games", "derived-games", Game);
+Paged_Sub_Resource!("A request for the records of every category of a game, created with [`GameData::records`]", GameRecords, "games", "records", Leaderboard);
+
+List_Request!("A request for a list of games, created with [`GameData::list`]", GameSearch, "games", Game);
+
+/// A request for a list of games in bulk mode, created with [`GameSearch::bulk`]
+#[derive(Debug, Clone)]
+pub struct BulkGameSearch{
+    query: Query,
 }
 
-#[derive(Debug)]
-pub enum GameError {
-    GameNotFound,
-    InvalidArguments,
-    ReqwestError(reqwest::Error),
+/// A request for every leaderboard of a game, created with [`GameData::boards`]
+#[derive(Debug, Clone)]
+pub struct GameBoards{
+    pub game: String,
 }
 
-/// Generates a function that gets a datype from the result.
+/// What a list of games can be sorted by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameOrder{
+    /// The english name
+    Name,
+    /// The japanese name
+    JapaneseName,
+    Abbreviation,
+    /// The year the game was released
+    Released,
+    /// When the game was added to speedrun.com
+    Created,
+    /// How close the name is to the name filter
+    Similarity,
+}
+
+impl GameOrder{
+    pub(crate) fn as_str(&self) -> &'static str{
+        match self{
+            GameOrder::Name => "name.int",
+            GameOrder::JapaneseName => "name.jap",
+            GameOrder::Abbreviation => "abbreviation",
+            GameOrder::Released => "released",
+            GameOrder::Created => "created",
+            GameOrder::Similarity => "similarity",
+        }
+    }
+}
+
+/// Which leaderboards [`GameRecords`] returns
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordScope{
+    /// Only the full game leaderboards
+    FullGame,
+    /// Only the leaderboards of levels
+    Levels,
+    /// Every leaderboard
+    All,
+}
+
+/// The assets a game can have, used by [`Game::assets`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameAsset{
+    Logo,
+    CoverTiny,
+    CoverSmall,
+    CoverMedium,
+    CoverLarge,
+    Icon,
+    Trophy1st,
+    Trophy2nd,
+    Trophy3rd,
+    Trophy4th,
+    Background,
+    Foreground,
+}
+
+/// Generates a function that gets a datype from the game.
 macro_rules! Generate_Function {
     ($doc:expr, $name:ident, $($field:tt).+, $datatype:ty $(, $args:ident($($extra_args:tt)*))?) => {
         #[doc = $doc]
         pub fn $name(&self) -> $datatype {
-            if let GameResult::Game(game_data) = self {
-                let value = game_data.data.$($field).+.clone();
-                $(let value = value.clone().$args($($extra_args)*);)?
-                value
-            } else {
-                panic!("Cannot get data from: {:?}", self);
-            }
+            let value = self.$($field).+.clone();
+            $(let value = value.clone().$args($($extra_args)*);)?
+            value
         }
     };
 }
 
-impl GameResult{
+impl Game{
 
    // Generate_Function!("Test", abbreviations, names.international, String);
    // Generate_Function!("## test2 ", data, platforms, Vec<String>, unwrap_or(vec!["None".to_string()]));
 
     Generate_Function!("Returns the name of the game \n ## Returns:  \n The name of the game in english as a string \n ## Example: \n
-    use speedrunapi::GameData;
-    let result = GameData::new(\"Mc\").run();
-    assert_eq!(result.name(), \"Minecraft: Java Edition\")", name, names.international, String);
-
-    Generate_Function!("Returns the japanese name of the game \n ## Returns: \n The name of the game in japanese as a string \n #### Notes:
-    \n The japanese name may be \"None\" \n ## Example: \n
-    use speedrunapi::GameData;
-    let result = GameData::new(\"Mc\").run();
-    assert_eq!(result.japanese_name(), \"None\")", japanese_name, names.japanese, String, unwrap_or(String::from("None")));
+```rust,no_run
+# #[tokio::main]
+# async fn main() -> Result<(), speedrunapi::Error> {
+use speedrunapi::{Client, GameData};
+let client = Client::new();
+let result = GameData::new(\"Mc\").send(&client).await?;
+assert_eq!(result.name(), \"Minecraft: Java Edition\");
+# Ok(())
+# }
+```", name, names.international, String);
+
+    Generate_Function!("Returns the japanese name of the game \n ## Returns: \n The name of the game in japanese as an Option<String> \n #### Notes:
+    \n The japanese name may be None \n ## Example: \n
+```rust,no_run
+# #[tokio::main]
+# async fn main() -> Result<(), speedrunapi::Error> {
+use speedrunapi::{Client, GameData};
+let client = Client::new();
+let result = GameData::new(\"Mc\").send(&client).await?;
+assert_eq!(result.japanese_name(), None);
+# Ok(())
+# }
+```", japanese_name, names.japanese, Option<String>);
 
     Generate_Function!("Returns the twitch name of the game \n ## Returns: \n The twitch name of the game as a String \n ## Example: \n
-    use speedrunapi::GameData;
-    let result = GameData::new(\"Mc\").run();
-    assert_eq!(result.twitch_name(), \"Minecraft\")", twitch_name, names.twitch, String);
+```rust,no_run
+# #[tokio::main]
+# async fn main() -> Result<(), speedrunapi::Error> {
+use speedrunapi::{Client, GameData};
+let client = Client::new();
+let result = GameData::new(\"Mc\").send(&client).await?;
+assert_eq!(result.twitch_name(), \"Minecraft\");
+# Ok(())
+# }
+```", twitch_name, names.twitch, String);
 
     Generate_Function!("Returns the ID of the game \n ## Returns: \n The ID of the game as a String \n ## Example: \n
-    use speedrunapi::GameData;
-    let result = GameData::new(\"Mc\").run();
-    assert_eq!(result.id(), \"j1npme6p\")", id, id, String);
-
-    Generate_Function!("Returns the number of boosts a game has received \n ## Returns: \n The number of boosts a game has recived as an i32 \n ## Example:
-    use speedrunapi::GameData;
-    let result = GameData::new(\"Mc\").run();
-    assert_eq!(result.boosts(), 0);", boosts, boosts_received, i32, unwrap_or(i32::from(0)));
-
-    Generate_Function!("Returns the number of unique players who have boosted a game \n ## Returns: \n The number of uniqe players who have boosted a game as an i32 \n ## Example:
-    use speedrunapi::GameData;
-    let result = GameData::new(\"Mc\").run();
-    assert_eq!(result.boosters(), 0);", boosters, boost_distinct_donors, i32, unwrap_or(i32::from(0)));
+```rust,no_run
+# #[tokio::main]
+# async fn main() -> Result<(), speedrunapi::Error> {
+use speedrunapi::{Client, GameData};
+let client = Client::new();
+let result = GameData::new(\"Mc\").send(&client).await?;
+assert_eq!(result.id(), \"j1npme6p\");
+# Ok(())
+# }
+```", id, id, String);
+
+    Generate_Function!("Returns the number of boosts a game has received \n ## Returns: \n The number of boosts a game has recived as an Option<i32> \n ## Example: \n
+```rust,no_run
+# #[tokio::main]
+# async fn main() -> Result<(), speedrunapi::Error> {
+use speedrunapi::{Client, GameData};
+let client = Client::new();
+let result = GameData::new(\"Mc\").send(&client).await?;
+assert_eq!(result.boosts(), Some(0));
+# Ok(())
+# }
+```", boosts, boosts_received, Option<i32>);
+
+    Generate_Function!("Returns the number of unique players who have boosted a game \n ## Returns: \n The number of uniqe players who have boosted a game as an Option<i32> \n ## Example: \n
+```rust,no_run
+# #[tokio::main]
+# async fn main() -> Result<(), speedrunapi::Error> {
+use speedrunapi::{Client, GameData};
+let client = Client::new();
+let result = GameData::new(\"Mc\").send(&client).await?;
+assert_eq!(result.boosters(), Some(0));
+# Ok(())
+# }
+```", boosters, boost_distinct_donors, Option<i32>);
 
     Generate_Function!("Returns the abbreviation of a game \n ## Returns: \n The abbreviation of a game as a string \n ## Example: \n
-    use speedrunapi::GameData;
-    let result = GameData::new(\"Mc\").run();
-    assert_eq!(result.abbreviation(), \"mc\")", abbreviation, abbreviation, String);
+```rust,no_run
+# #[tokio::main]
+# async fn main() -> Result<(), speedrunapi::Error> {
+use speedrunapi::{Client, GameData};
+let client = Client::new();
+let result = GameData::new(\"Mc\").send(&client).await?;
+assert_eq!(result.abbreviation(), \"mc\");
+# Ok(())
+# }
+```", abbreviation, abbreviation, String);
 
     Generate_Function!("Returns the weblink of a game \n ## Returns: \n The weblink of a game as a string \n ## Example: \n
-    use speedrunapi::GameData;
-    let result = GameData::new(\"Mc\").run();
-    assert_eq!(result.weblink(), \"https://www.speedrun.com/mc\")", weblink, weblink, String);
-
-    Generate_Function!("Returns the game discord \n ## Returns: \n The discord of a game as a string \n ## Example: \n
-    use speedrunapi::GameData;
-    let result = GameData::new(\"Mc\").run();
-    assert_eq!(result.discord(), \"https://discord.gg/jmdFn3C\")", discord , discord, String);
+```rust,no_run
+# #[tokio::main]
+# async fn main() -> Result<(), speedrunapi::Error> {
+use speedrunapi::{Client, GameData};
+let client = Client::new();
+let result = GameData::new(\"Mc\").send(&client).await?;
+assert_eq!(result.weblink(), \"https://www.speedrun.com/mc\");
+# Ok(())
+# }
+```", weblink, weblink, String);
+
+    Generate_Function!("Returns the game discord \n ## Returns: \n The discord of a game as an Option<String>, None if the game has none \n ## Example: \n
+```rust,no_run
+# #[tokio::main]
+# async fn main() -> Result<(), speedrunapi::Error> {
+use speedrunapi::{Client, GameData};
+let client = Client::new();
+let result = GameData::new(\"Mc\").send(&client).await?;
+assert_eq!(result.discord().as_deref(), Some(\"https://discord.gg/jmdFn3C\"));
+# Ok(())
+# }
+```", discord , discord, Option<String>);
 
     Generate_Function!("Returns the date the game was released \n ## Returns: \n The year the game was released as a i16 \n ## Example: \n
-    use speedrunapi::GameData;
-    let result = GameData::new(\"Mc\").run();
-    assert_eq!(result.released(), 2011)", released, released, i16);
-
-    Generate_Function!("Returns the date the game was released \n ## Returns: \n the date the game was released as a string \n Note:
-    This function is similar to the released function it is just newer and more specific, so some games have yet to migrate or set it so it can be \"None\" \n ## Example: \n
-    use speedrunapi::GameData;
-    let result = GameData::new(\"Mc\").run();
-    assert_eq!(result.release_date(), \"None\")", release_date, release_date, String, unwrap_or(String::from("None")));
+```rust,no_run
+# #[tokio::main]
+# async fn main() -> Result<(), speedrunapi::Error> {
+use speedrunapi::{Client, GameData};
+let client = Client::new();
+let result = GameData::new(\"Mc\").send(&client).await?;
+assert_eq!(result.released(), 2011);
+# Ok(())
+# }
+```", released, released, i16);
+
+    Generate_Function!("Returns the date the game was released \n ## Returns: \n the date the game was released as an Option<String> \n Note:
+    This function is similar to the released function it is just newer and more specific, so some games have yet to migrate or set it so it can be None \n ## Example: \n
+```rust,no_run
+# #[tokio::main]
+# async fn main() -> Result<(), speedrunapi::Error> {
+use speedrunapi::{Client, GameData};
+let client = Client::new();
+let result = GameData::new(\"Mc\").send(&client).await?;
+assert_eq!(result.release_date().as_deref(), Some(\"2011-11-18\"));
+# Ok(())
+# }
+```", release_date, release_date, Option<String>);
 
     /*
     // Figure out how to be able to return multiple values
     pub fn ruleset(&self, rule: String) {
-        if let GameResult::Game(game_data) = self {
-            match rule.to_lowercase().as_str(){
+        match rule.to_lowercase().as_str(){
 
-            }
-        }
-        else{
-            panic!("Cannot get ruleset from: {:?}", self);
         }
     }
     */
 
     Generate_Function!("Returns if the game has romhacks \n ## Returns: \n If the game has romhacks as a bool 
     \n Note: Use gametypes for more information \n ## Example: \n
-    use speedrunapi::GameData;
-    let result = GameData::new(\"Mc\").run();
-    assert_eq!(result.romhack(), false)", romhack, romhack, bool);
-
-    Generate_Function!("Returns the gametypes for a game \n ## Returns: \n The game types of a game in a vec of strings 
-    \n If there is none listed defults to \\[\"None\"\\] or \\[\\] \n ## Example: \n
-    use speedrunapi::GameData;
-    let result: Vec<String> = GameData::new(\"Mc\").run().gametypes();
-    assert_eq!(result, Vec::<String>::new())", gametypes, gametypes, Vec<String>, unwrap_or(vec!["None".to_string()]));
-
-    Generate_Function!("Returns the platforms of a game \n ## Returns: \n the platforms of a game in a vec of strings
-    \n If there is none listed defults to \\[\"None\"\\] or \\[\\] \n ## Example: \n
-    use speedrunapi::GameData;
-    let result: Vec<String>  = GameData::new(\"Mc\").run().platforms();
-    assert_eq!(result, [\"8gej2n93\"])", platforms, platforms, Vec<String>, unwrap_or(vec!["None".to_string()]));
-
-    Generate_Function!("Returns the regions of a game \n ## Returns: \n the regions of a game as a Vec<String>
-    \n If there is none listed defults to \\[\"None\"\\] or \\[\\] \n ## Example: \n
-    use speedrunapi::GameData;
-    let result: Vec<String> = GameData::new(\"Mc\").run().regions();
-    assert_eq!(result, Vec::<String>::new())", regions, regions, Vec<String>, unwrap_or(vec!["None".to_string()]));
-
-    Generate_Function!("Returns the genres of a game \n ## Returns: \n the genres of a game as a Vec<String>
-    \n If there is none listed defults to \\[\"None\"\\] or \\[\\] \n ## Example: \n
-    use speedrunapi::GameData;
-    let result: Vec<String> = GameData::new(\"Mc\").run().genres();
-    assert_eq!(result, [\"q4n60ln9\", \"jp230326\"])", genres, genres, Vec<String>, unwrap_or(vec!["None".to_string()]));
-
-    Generate_Function!("Returns the engines of a game \n ## Returns: \n the engines of a game as a Vec<String>
-    \n If there is none listed defults to \\[\"None\"\\] or \\[\\] \n ## Example: \n
-    use speedrunapi::GameData;
-    let result: Vec<String> = GameData::new(\"Mc\").run().engines();
-    assert_eq!(result, Vec::<String>::new())", engines, engines, Vec<String>, unwrap_or(vec!["None".to_string()]));
-
-    Generate_Function!("Returns the developers of a game \n ## Returns: \n the developers of a game as a Vec<String>
-    \n If there is none listed defults to \\[\"None\"\\] or \\[\\] \n ## Example: \n
-    use speedrunapi::GameData;
-    let result: Vec<String> = GameData::new(\"Mc\").run().developers();
-    assert_eq!(result, [\"k62d97ex\"])", developers, developers, Vec<String>, unwrap_or(vec!["None".to_string()]));
-
-    Generate_Function!("Returns the publishers of a game \n ## Returns: \n the publishers of a game as a Vec<String>
-    \n If there is none listed defults to \\[\"None\"\\] or \\[\\] \n ## Example: \n
-    use speedrunapi::GameData;
-    let result: Vec<String> = GameData::new(\"Mc\").run().publishers();
-    assert_eq!(result, Vec::<String>::new())", publishers, publishers, Vec<String>, unwrap_or(vec!["None".to_string()]));
+```rust,no_run
+# #[tokio::main]
+# async fn main() -> Result<(), speedrunapi::Error> {
+use speedrunapi::{Client, GameData};
+let client = Client::new();
+let result = GameData::new(\"Mc\").send(&client).await?;
+assert_eq!(result.romhack(), false);
+# Ok(())
+# }
+```", romhack, romhack, bool);
+
+    Generate_Function!("Returns the gametypes for a game \n ## Returns: \n The game types of a game as an Option<Vec<String>> 
+    \n If speedrun.com does not list them returns None \n ## Example: \n
+```rust,no_run
+# #[tokio::main]
+# async fn main() -> Result<(), speedrunapi::Error> {
+use speedrunapi::{Client, GameData};
+let client = Client::new();
+let result = GameData::new(\"Mc\").send(&client).await?.gametypes();
+assert_eq!(result, Some(Vec::new()));
+# Ok(())
+# }
+```", gametypes, gametypes, Option<Vec<String>>);
+
+    Generate_Function!("Returns the platforms of a game \n ## Returns: \n the platforms of a game as an Option<Vec<String>>
+    \n If speedrun.com does not list them returns None \n ## Example: \n
+```rust,no_run
+# #[tokio::main]
+# async fn main() -> Result<(), speedrunapi::Error> {
+use speedrunapi::{Client, GameData};
+let client = Client::new();
+let result = GameData::new(\"Mc\").send(&client).await?.platforms();
+assert_eq!(result.unwrap(), [\"8gej2n93\"]);
+# Ok(())
+# }
+```", platforms, platforms, Option<Vec<String>>);
+
+    Generate_Function!("Returns the regions of a game \n ## Returns: \n the regions of a game as an Option<Vec<String>>
+    \n If speedrun.com does not list them returns None \n ## Example: \n
+```rust,no_run
+# #[tokio::main]
+# async fn main() -> Result<(), speedrunapi::Error> {
+use speedrunapi::{Client, GameData};
+let client = Client::new();
+let result = GameData::new(\"Mc\").send(&client).await?.regions();
+assert_eq!(result, Some(Vec::new()));
+# Ok(())
+# }
+```", regions, regions, Option<Vec<String>>);
+
+    Generate_Function!("Returns the genres of a game \n ## Returns: \n the genres of a game as an Option<Vec<String>>
+    \n If speedrun.com does not list them returns None \n ## Example: \n
+```rust,no_run
+# #[tokio::main]
+# async fn main() -> Result<(), speedrunapi::Error> {
+use speedrunapi::{Client, GameData};
+let client = Client::new();
+let result = GameData::new(\"Mc\").send(&client).await?.genres();
+assert_eq!(result.unwrap(), [\"q4n60ln9\", \"jp230326\"]);
+# Ok(())
+# }
+```", genres, genres, Option<Vec<String>>);
+
+    Generate_Function!("Returns the engines of a game \n ## Returns: \n the engines of a game as an Option<Vec<String>>
+    \n If speedrun.com does not list them returns None \n ## Example: \n
+```rust,no_run
+# #[tokio::main]
+# async fn main() -> Result<(), speedrunapi::Error> {
+use speedrunapi::{Client, GameData};
+let client = Client::new();
+let result = GameData::new(\"Mc\").send(&client).await?.engines();
+assert_eq!(result, Some(Vec::new()));
+# Ok(())
+# }
+```", engines, engines, Option<Vec<String>>);
+
+    Generate_Function!("Returns the developers of a game \n ## Returns: \n the developers of a game as an Option<Vec<String>>
+    \n If speedrun.com does not list them returns None \n ## Example: \n
+```rust,no_run
+# #[tokio::main]
+# async fn main() -> Result<(), speedrunapi::Error> {
+use speedrunapi::{Client, GameData};
+let client = Client::new();
+let result = GameData::new(\"Mc\").send(&client).await?.developers();
+assert_eq!(result.unwrap(), [\"k62d97ex\"]);
+# Ok(())
+# }
+```", developers, developers, Option<Vec<String>>);
+
+    Generate_Function!("Returns the publishers of a game \n ## Returns: \n the publishers of a game as an Option<Vec<String>>
+    \n If speedrun.com does not list them returns None \n ## Example: \n
+```rust,no_run
+# #[tokio::main]
+# async fn main() -> Result<(), speedrunapi::Error> {
+use speedrunapi::{Client, GameData};
+let client = Client::new();
+let result = GameData::new(\"Mc\").send(&client).await?.publishers();
+assert_eq!(result, Some(Vec::new()));
+# Ok(())
+# }
+```", publishers, publishers, Option<Vec<String>>);
 
     /// Returns the moderators of a game
     /// 
     /// ## Returns:
     /// 
-    /// The moderators and their positions of a game as a Vec<(String, String)>
+    /// The ids of the moderators of a game and their roles as a Vec<(String, Option<ModeratorRole>)>
+    /// - The roles are None if the moderators were embedded, speedrun.com does not return them with embedded moderators
     /// 
     /// ## Example:
-    /// ```rust
-    /// use speedrunapi::GameData;
-    /// let result = GameData::new("Mc").run().moderators();
-    /// println!("{:?}", result);
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, GameData, ModeratorRole};
+    /// let client = Client::new();
+    /// let result = GameData::new("Mc").send(&client).await?.moderators();
+    /// for (id, role) in result{
+    ///     if role == Some(ModeratorRole::SuperModerator){
+    ///         println!("{} is a super moderator", id);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
     /// ```
-    
-    pub fn moderators(&self) -> Vec<(String, String)> {
-        //for translations
-        //use crate::user_data::UserData;
-        if let GameResult::Game(game_data) = self {
-            let mods = game_data.data.moderators.clone();
-            let ids: Vec<String> = mods.iter().map(|x| x.0.to_string()).collect();
-            let positions: Vec<String> = mods.iter().map(|x| x.1.to_string()).collect();
-            for pos in 1..positions.len(){
-                positions[pos].trim_matches(|c| c == '\\' || c == '"').to_string();
-            }
-            let merged_vec: Vec<(String, String)> = ids.iter().zip(positions.iter()).map(|(x, y)| (x.to_string(), y.to_string())).collect();
-            return merged_vec.to_vec();
-        }
-        else{
-            panic!("Cannot get moderators from: {:?}", self);
-        }
+    pub fn moderators(&self) -> Vec<(String, Option<ModeratorRole>)> {
+        self.moderators.iter().map(|(id, role)| (id.clone(), *role)).collect()
     }
     
     /// Returns when the game was created on speedrun.com
     /// 
     /// ## Returns:
     /// 
-    /// The date the game was created as a string
+    /// The date the game was created as an Option<String>
+    /// - For games added before speedrun.com recorded it the date is None
+    /// - A date that is not in the format rfc3339 is returned as speedrun.com sent it
     /// 
     /// ## Example:
-    /// ```rust
-    /// use speedrunapi::GameData;
-    /// let result: String = GameData::new("Mc").run().created();
-    /// assert_eq!(result, "2015-01-29 23:41:21")
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, GameData};
+    /// let client = Client::new();
+    /// let result = GameData::new("Mc").send(&client).await?.created();
+    /// assert_eq!(result.as_deref(), Some("2015-01-29 23:41:21"));
+    /// # Ok(())
+    /// # }
     /// ```
-    
-    pub fn created(&self) -> String{
-        if let GameResult::Game(game_data) = self{
-            tl_time!(game_data.data.created)
-        }
-        else {
-            panic!("Cannot get creation date from: {:?}", self)
-        }
+    pub fn created(&self) -> Option<String>{
+        self.created.as_deref().map(translate::time)
     }
     
     /// Returns the games assets
     /// 
     /// ## Arguments:
     /// 
-    /// `asset_type: &str`: The type of assets to return (NON CASE SENSITIVE)
+    /// `asset_type: GameAsset`: The type of assets to return
     /// asset_type can be: 
-    /// - logo
-    /// - cover_tiny
-    /// - cover_small
-    /// - cover_medium
-    /// - cover_large
-    /// - icon
-    /// - trophy_1st
-    /// - trophy_2nd
-    /// - trophy_3rd
-    /// - trophy_4th
-    /// - background
-    /// - foreground
+    /// - GameAsset::Logo
+    /// - GameAsset::CoverTiny
+    /// - GameAsset::CoverSmall
+    /// - GameAsset::CoverMedium
+    /// - GameAsset::CoverLarge
+    /// - GameAsset::Icon
+    /// - GameAsset::Trophy1st
+    /// - GameAsset::Trophy2nd
+    /// - GameAsset::Trophy3rd
+    /// - GameAsset::Trophy4th
+    /// - GameAsset::Background
+    /// - GameAsset::Foreground
     /// 
     /// ## Returns:
     /// 
-    /// The link to the asset as a string
+    /// The link to the asset as an Option<String>
+    /// The link is None if the game does not have that asset
     /// 
     /// ## Example:
-    /// ```rust
-    /// use speedrunapi::GameData;
-    /// let result: String = GameData::new("Mc").run().assets("logo");
-    /// assert_eq!(result, "https://www.speedrun.com/themeasset/2wo6q4we/logo?v=413b0b3")
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, GameData};
+    /// use speedrunapi::game_data::GameAsset;
+    /// let client = Client::new();
+    /// let result = GameData::new("Mc").send(&client).await?.assets(GameAsset::Logo);
+    /// assert_eq!(result.as_deref(), Some("https://www.speedrun.com/themeasset/2wo6q4we/logo?v=413b0b3"));
+    /// # Ok(())
+    /// # }
     /// ```
-    /// 
-    /// ## Panics!
-    /// 
-    /// The function will panic if it recives an invalid asset type or no asset type at all!
-    
-    pub fn assets(&self, asset_type: &str) -> String{
-        if let GameResult::Game(game_data) = self {
-            match asset_type.to_lowercase().as_str(){
-                "logo" => game_data.data.assets.logo.uri.clone().unwrap_or(String::from("None")),
-                "cover_tiny" => game_data.data.assets.cover_tiny.uri.clone().unwrap_or(String::from("None")),
-                "cover_small" => game_data.data.assets.cover_small.uri.clone().unwrap_or(String::from("None")),
-                "cover_medium" => game_data.data.assets.cover_medium.uri.clone().unwrap_or(String::from("None")),
-                "cover_large" => game_data.data.assets.cover_large.uri.clone().unwrap_or(String::from("None")),
-                "icon" => game_data.data.assets.icon.uri.clone().unwrap_or(String::from("None")),
-                "trophy_1st" => game_data.data.assets.trophy_1st.uri.clone().unwrap_or(String::from("None")),
-                "trophy_2nd" => game_data.data.assets.trophy_2nd.uri.clone().unwrap_or(String::from("None")),
-                "trophy_3rd" => game_data.data.assets.trophy_3rd.uri.clone().unwrap_or(String::from("None")),
-                "trophy_4th" => game_data.data.assets.trophy_4th.iter().map(|link| link.uri.clone().unwrap_or(String::from("None"))).collect(),
-                "background" => game_data.data.assets.background.uri.clone().unwrap_or(String::from("None")),
-                "foreground" => game_data.data.assets.foreground.iter().map(|link| link.uri.clone().unwrap_or(String::from("None"))).collect(),
-                _ => panic!("Invalid asset type: {:?}", asset_type)
-            }
-       }
-        else{
-            panic!("Cannot get assets from: {:?}", self);
+    pub fn assets(&self, asset_type: GameAsset) -> Option<String>{
+        match asset_type{
+            GameAsset::Logo => self.assets.logo.uri.clone(),
+            GameAsset::CoverTiny => self.assets.cover_tiny.uri.clone(),
+            GameAsset::CoverSmall => self.assets.cover_small.uri.clone(),
+            GameAsset::CoverMedium => self.assets.cover_medium.uri.clone(),
+            GameAsset::CoverLarge => self.assets.cover_large.uri.clone(),
+            GameAsset::Icon => self.assets.icon.uri.clone(),
+            GameAsset::Trophy1st => self.assets.trophy_1st.uri.clone(),
+            GameAsset::Trophy2nd => self.assets.trophy_2nd.uri.clone(),
+            GameAsset::Trophy3rd => self.assets.trophy_3rd.uri.clone(),
+            GameAsset::Trophy4th => self.assets.trophy_4th.as_ref().and_then(|link| link.uri.clone()),
+            GameAsset::Background => self.assets.background.uri.clone(),
+            GameAsset::Foreground => self.assets.foreground.as_ref().and_then(|link| link.uri.clone()),
         }
     }
 
@@ -291,23 +508,22 @@ impl GameResult{
     /// The format is (name, link)
     /// 
     /// ## Example:
-    /// ```rust
-    /// use speedrunapi::GameData;
-    /// let result: Vec<(String, String)> = GameData::new("Mc").run().links();
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, GameData};
+    /// let client = Client::new();
+    /// let result: Vec<(String, String)> = GameData::new("Mc").send(&client).await?.links();
     /// println!("{:?}", result);
+    /// # Ok(())
+    /// # }
     /// ```
-
     pub fn links(&self) -> Vec<(String, String)> {
-        if let GameResult::Game(game_data) = self {
-            let game = &game_data.data.links;
-            let names: Vec<&str> = game.iter().map(|link| link.rel.as_str()).collect();
-            let urls: Vec<&str> = game.iter().map(|link| link.uri.as_str()).collect();
-            let merged_vec: Vec<(String, String)> = names.iter().zip(urls.iter()).map(|(x, y)| (x.to_string(), y.to_string())).collect();
-            return merged_vec.to_vec();
-        }
-        else{
-            panic!("Cannot get links from: {:?}", self);
-        }
+        let game = &self.links;
+        let names: Vec<&str> = game.iter().map(|link| link.rel.as_str()).collect();
+        let urls: Vec<&str> = game.iter().map(|link| link.uri.as_str()).collect();
+        let merged_vec: Vec<(String, String)> = names.iter().zip(urls.iter()).map(|(x, y)| (x.to_string(), y.to_string())).collect();
+        merged_vec
     }
 
 }
@@ -326,21 +542,159 @@ impl GameData{
     /// let result = GameData::new("Mc");
     /// ```
     /// This will create a new GameData object with the name "Mc" but when printed will only print out the parameters passed to it.
-    /// To show the date recieved you need to add .run()
-    /// ```rust
-    /// use speedrunapi::GameData;
-    /// let result = GameData::new("Mc").run();
+    /// To show the date recieved you need to add .send(&client).await
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, GameData};
+    /// let client = Client::new();
+    /// let result = GameData::new("Mc").send(&client).await?;
     /// println!("{:?}", result);
+    /// # Ok(())
+    /// # }
     /// ```
-
     pub fn new(game: &str) -> GameData{
         GameData{
             name: game.to_string(),
-            categories: false,
-            levels: false,
-            variables: false,
-            derived_games: false,
-            records: false,
+            query: Query::default(),
+        }
+    }
+
+    /// Creates a request for a list of games
+    /// 
+    /// The games can be filtered by name, abbreviation, release year, gametype, platform, region, genre, engine,
+    /// developer, publisher, moderator and if they are romhacks.
+    /// 
+    /// # Example:
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, Direction, GameData};
+    /// use speedrunapi::game_data::GameOrder;
+    /// let client = Client::new();
+    /// let result = GameData::list()
+    ///     .platform("8gej2n93")
+    ///     .released(2011)
+    ///     .order(GameOrder::Name, Direction::Ascending)
+    ///     .send(&client)
+    ///     .await?;
+    /// println!("{:?}", result);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list() -> GameSearch{
+        GameSearch::new()
+    }
+
+    /// Creates a request for the categories of the game
+    /// 
+    /// # Example:
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, GameData};
+    /// let client = Client::new();
+    /// let result = GameData::new("mc").categories().miscellaneous(false).send(&client).await?;
+    /// println!("{:?}", result);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn categories(&self) -> GameCategories{
+        GameCategories::new(&self.name)
+    }
+
+    /// Creates a request for the levels of the game
+    /// 
+    /// # Example:
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, GameData};
+    /// let client = Client::new();
+    /// let result = GameData::new("smo").levels().send(&client).await?;
+    /// println!("{:?}", result);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn levels(&self) -> GameLevels{
+        GameLevels::new(&self.name)
+    }
+
+    /// Creates a request for the variables of the game
+    /// 
+    /// # Example:
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, GameData};
+    /// let client = Client::new();
+    /// let result = GameData::new("mc").variables().send(&client).await?;
+    /// println!("{:?}", result);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn variables(&self) -> GameVariables{
+        GameVariables::new(&self.name)
+    }
+
+    /// Creates a request for the games derived from the game, such as its romhacks and category extensions
+    /// 
+    /// # Example:
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, GameData};
+    /// let client = Client::new();
+    /// let result = GameData::new("mc").derived_games().send(&client).await?;
+    /// println!("{:?}", result);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn derived_games(&self) -> DerivedGames{
+        DerivedGames::new(&self.name)
+    }
+
+    /// Creates a request for the records of the game, one leaderboard for every category
+    /// 
+    /// # Example:
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, GameData};
+    /// let client = Client::new();
+    /// let result = GameData::new("mc").records().top(1).send(&client).await?;
+    /// for leaderboard in result{
+    ///     println!("{}: {:?}", leaderboard.category, leaderboard.runs.first().map(|entry| entry.run.time()));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn records(&self) -> GameRecords{
+        GameRecords::new(&self.name)
+    }
+
+    /// Creates a request for every leaderboard of the game
+    /// 
+    /// Every category is combined with every level it is for and every value of the subcategories that apply to it,
+    /// giving the same list of leaderboards the website shows.
+    /// 
+    /// # Example:
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, GameData};
+    /// let client = Client::new();
+    /// let result = GameData::new("mc").boards().send(&client).await?;
+    /// for board in result{
+    ///     println!("{}", board.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// This will print the name of every leaderboard of Minecraft: Java Edition, such as "Any% Glitchless - 1.16+"
+    pub fn boards(&self) -> GameBoards{
+        GameBoards{
+            game: self.name.clone(),
         }
     }
 
@@ -352,57 +706,224 @@ impl GameData{
     /// 
     /// # Returns:
     /// 
-    /// The result of the request as a GameData object
-    /// 
-    /// If an error has occurred the program may return None or Error(ErrorType) as an OK
+    /// The game, or the [`Error`] if the request failed or the game could not be found
     /// 
     /// # Example:
-    /// ```rust
-    /// use speedrunapi::GameData;
-    /// let result = GameData::new("Mc").run();
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, GameData};
+    /// let client = Client::new();
+    /// let result = GameData::new("Mc").send(&client).await?;
     /// println!("{:?}", result);
+    /// # Ok(())
+    /// # }
     /// ```
-    
-    #[tokio::main]
-    pub async fn run(&self) -> GameResult{
-        let client = reqwest::Client::new();
-        let mut url = format!("https://www.speedrun.com/api/v1/games/{:1}", self.name);
-        if self.categories{
-            url.push_str("/categories");
-        }
-        if self.levels{
-            url.push_str("/levels");
-        }
-        if self.variables{
-            url.push_str("/variables");
-        }
-        if self.derived_games{
-            url.push_str("/derived-games");
-        }
-        if self.records{
-            url.push_str("/records");
-        }
-        else{
-            url.to_string();
+    pub async fn send(&self, client: &Client) -> Result<Game, Error>{
+        let path = self.query.path(&format!("/games/{:1}", self.name));
+        let response = client.get_json::<Data<Game>>(&path).await?;
+        Ok(response.data)
+    }
+
+    Embed_Function!(GameEmbed);
+
+    Blocking_Function!(Result<Game, Error>);
+}
+
+impl GameLevels{
+    Embed_Function!(LevelEmbed);
+}
+
+impl DerivedGames{
+    Embed_Function!(GameEmbed);
+}
+
+impl GameSearch{
+    Game_Filters!();
+
+    Embed_Function!(GameEmbed);
+
+    /// Switches the request to bulk mode
+    /// 
+    /// In bulk mode speedrun.com only returns the id, names, abbreviation and weblink of each game,
+    /// but allows up to 1000 games a page, which makes it the quickest way to list every game.
+    /// 
+    /// # Example:
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, GameData};
+    /// let client = Client::new();
+    /// let result = GameData::list().bulk().max(1000).send(&client).await?;
+    /// println!("{}", result.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn bulk(mut self) -> BulkGameSearch{
+        self.query.set_bool("_bulk", true);
+        BulkGameSearch{
+            query: self.query,
         }
-        let response = match client.get(url).send().await{
-            Ok(response) => response,
-            Err(err) => return GameResult::Error(GameError::ReqwestError(err.into())),
+    }
+}
+
+impl BulkGameSearch{
+
+    fn path(&self) -> String{
+        self.query.path("/games")
+    }
+
+    Page_Functions!(BulkGame);
+}
+
+impl GameCategories{
+
+    /// Only returns the miscellaneous categories if true, or only the main categories if false
+    pub fn miscellaneous(mut self, miscellaneous: bool) -> Self{
+        self.query.set_bool("miscellaneous", miscellaneous);
+        self
+    }
+
+    Embed_Function!(CategoryEmbed);
+}
+
+impl GameRecords{
+
+    /// Only returns the runs in the top places of each leaderboard
+    pub fn top(mut self, top: u32) -> Self{
+        self.query.set("top", &top.to_string());
+        self
+    }
+
+    /// Picks if the full game leaderboards, the level leaderboards or both are returned
+    pub fn scope(mut self, scope: RecordScope) -> Self{
+        let scope = match scope{
+            RecordScope::FullGame => "full-game",
+            RecordScope::Levels => "levels",
+            RecordScope::All => "all",
         };
-        if response.status() == reqwest::StatusCode::NOT_FOUND{
-            return GameResult::Error(GameError::GameNotFound);
+        self.query.set("scope", scope);
+        self
+    }
+
+    /// Leaves out the miscellaneous categories if false
+    pub fn miscellaneous(mut self, miscellaneous: bool) -> Self{
+        self.query.set_bool("miscellaneous", miscellaneous);
+        self
+    }
+
+    /// Leaves out the leaderboards that have no runs if true
+    pub fn skip_empty(mut self, skip_empty: bool) -> Self{
+        self.query.set_bool("skip-empty", skip_empty);
+        self
+    }
+
+    Embed_Function!(LeaderboardEmbed);
+}
+
+impl GameBoards{
+
+    /// Runs the requests to the speedrun.com API
+    /// 
+    /// The categories, levels and variables of the game are fetched at the same time and then combined.
+    /// 
+    /// # Returns:
+    /// 
+    /// Every leaderboard of the game, or the [`Error`] if one of the requests failed
+    pub async fn send(&self, client: &Client) -> Result<Vec<Board>, Error>{
+        let (categories, levels, variables) = (
+            GameCategories::new(&self.game),
+            GameLevels::new(&self.game),
+            GameVariables::new(&self.game),
+        );
+        let (categories, levels, variables) = tokio::try_join!(
+            categories.send(client),
+            levels.send(client),
+            variables.send(client),
+        )?;
+        Ok(boards(&self.game, &categories, &levels, &variables))
+    }
+
+    Blocking_Function!(Result<Vec<Board>, Error>);
+}
+
+impl Board{
+
+    /// Creates the request for the leaderboard
+    /// 
+    /// ## Example:
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, GameData};
+    /// let client = Client::new();
+    /// for board in GameData::new("mc").boards().send(&client).await?{
+    ///     let leaderboard = board.leaderboard().top(1).send(&client).await?;
+    ///     println!("{}: {:?}", board.name, leaderboard.runs.first().map(|entry| entry.run.time()));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn leaderboard(&self) -> LeaderboardData{
+        let mut leaderboard = LeaderboardData::new(&self.game, &self.category);
+        if let Some(level) = &self.level{
+            leaderboard = leaderboard.level(level);
         }
-        if self.categories{
-            // for now print later on deseralize
-            println!("test");
-            GameResult::None
+        for (variable, value) in &self.values{
+            leaderboard = leaderboard.variable(variable, value);
         }
-        else{
-            let response = match response.json::<Data>().await{
-                Ok(response) => response,
-                Err(err) => return GameResult::Error(GameError::ReqwestError(err.into())),
+        leaderboard
+    }
+}
+
+/// Combines every category with its levels and the values of its subcategories
+fn boards(game: &str, categories: &[Category], levels: &[Level], variables: &[Variable]) -> Vec<Board>{
+    let mut boards = Vec::new();
+    for category in categories{
+        let targets: Vec<Option<&Level>> = match category.category_type{
+            CategoryType::PerGame => vec![None],
+            CategoryType::PerLevel => levels.iter().map(Some).collect(),
+        };
+        for level in targets{
+            let level_id = level.map(|level| level.id.as_str());
+            let subcategories = variables.iter()
+                .filter(|variable| variable.is_subcategory && !variable.values.values.is_empty())
+                .filter(|variable| variable.category.as_ref().is_none_or(|id| *id == category.id))
+                .filter(|variable| variable.applies_to(level_id));
+
+            // Every combination of values, with the labels used for the name
+            let mut combinations = vec![(BTreeMap::new(), Vec::new())];
+            for variable in subcategories{
+                combinations = combinations.into_iter().flat_map(|(values, labels)| {
+                    variable.values.values.iter().map(move |(id, value)| {
+                        let mut values = values.clone();
+                        let mut labels = labels.clone();
+                        values.insert(variable.id.clone(), id.clone());
+                        labels.push(value.label.as_str());
+                        (values, labels)
+                    })
+                }).collect();
+            }
+
+            let base = match level{
+                Some(level) => format!("{}: {}", level.name, category.name),
+                None => category.name.clone(),
             };
-            GameResult::Game(response)
+            for (values, labels) in combinations{
+                let mut name = base.clone();
+                for label in labels{
+                    name.push_str(" - ");
+                    name.push_str(label);
+                }
+                boards.push(Board{
+                    name,
+                    game: game.to_string(),
+                    category: category.id.clone(),
+                    level: level.map(|level| level.id.clone()),
+                    values,
+                });
+            }
         }
     }
+    boards
 }
@@ -5,39 +5,34 @@
 //! # Arguments:
 //! 
 //! When calling for Gametypes first asing a variable to GameTypeData after importing it. 
-//! After that add ::new("gametype_name/id") and call .run() to get the data.
+//! After that add ::new("gametype_name/id") and call .send(&client).await with a [`Client`](crate::Client) to get the data.
 //! 
 //! # Example:
 //! 
-//! ```rust
-//! use speedrunapi::GameTypeData;
-//! let result = GameTypeData::new("Fangame").run();
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), speedrunapi::Error> {
+//! use speedrunapi::{Client, GameTypeData};
+//! let client = Client::new();
+//! let result = GameTypeData::new("Fangame").send(&client).await?;
 //! println!("{:?}", result);
+//! # Ok(())
+//! # }
 //! ```
 //! This will fetch the entirenty of the data for the gametype as a json object and print it.
 
-use crate::types::GameTypeData as Data;
+use crate::types::Data;
+use crate::types::GameType;
+use crate::{Client, Direction, Error};
 
 #[derive(Debug)]
 pub struct GameTypeData{
     pub gametype: String,
 }
 
-#[derive(Debug)]
-pub enum GameTypeError {
-    GameTypeNotFound,
-    InvalidArguments,
-    ReqwestError(reqwest::Error),
-}
-
-#[derive(Debug)]
-pub enum GameTypeResult {
-    GameType(Data),
-    None,
-    Error(GameTypeError),
-}
+List_Request!("A request for every gametype, created with [`GameTypeData::list`]", GameTypeList, "gametypes", GameType);
 
-impl GameTypeResult{
+impl GameType{
     
     /// Returns the name of the gametype
     /// 
@@ -46,19 +41,18 @@ impl GameTypeResult{
     /// The name of the gametype as an &str
     /// 
     /// ## Example:
-    /// ```rust
-    /// use speedrunapi::GameTypeData;
-    /// let result = GameTypeData::new("Fangame").run();
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, GameTypeData};
+    /// let client = Client::new();
+    /// let result = GameTypeData::new("Fangame").send(&client).await?;
     /// assert_eq!(result.name(), "Fangame");
+    /// # Ok(())
+    /// # }
     /// ```
-    
     pub fn name(&self) -> &str{
-        if let GameTypeResult::GameType(gametype_data) = self{
-            &gametype_data.data.name
-        }
-        else{
-            panic!("Cannot Get name from: {:?}", self);
-        }
+        &self.name
     }
 
     /// Returns the id of a gametype
@@ -68,19 +62,18 @@ impl GameTypeResult{
     /// The id of the gametype as a &str
     /// 
     /// ## Example:
-    /// ```rust
-    /// use speedrunapi::GameTypeData;
-    /// let result = GameTypeData::new("Fangame").run();
-    /// assert_eq!(result.id(), "d91jd1ex")
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, GameTypeData};
+    /// let client = Client::new();
+    /// let result = GameTypeData::new("Fangame").send(&client).await?;
+    /// assert_eq!(result.id(), "d91jd1ex");
+    /// # Ok(())
+    /// # }
     /// ```
-    
     pub fn id(&self) -> &str{
-        if let GameTypeResult::GameType(gametype_data) = self{
-            &gametype_data.data.id
-        }
-        else{
-            panic!("Cannot Get id from: {:?}", self);
-        }
+        &self.id
     }
 }
 
@@ -99,19 +92,42 @@ impl GameTypeData{
     /// println!("{:?}", result);
     /// ```
     /// This will return the parameters passed to the function
-    /// ```rust
-    /// use speedrunapi::GameTypeData;
-    /// let result = GameTypeData::new("Fangame").run();
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, GameTypeData};
+    /// let client = Client::new();
+    /// let result = GameTypeData::new("Fangame").send(&client).await?;
     /// println!("{:?}", result);
+    /// # Ok(())
+    /// # }
     /// ```
     /// This will print the JSON data of the gametype
-
     pub fn new(gametype: &str) -> GameTypeData{
         GameTypeData{
             gametype: gametype.to_string(),
         }
     }
 
+    /// Creates a request for every gametype
+    /// 
+    /// # Example:
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, GameTypeData};
+    /// let client = Client::new();
+    /// let result = GameTypeData::list().send(&client).await?;
+    /// for gametype in result{
+    ///     println!("{}", gametype.name());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list() -> GameTypeList{
+        GameTypeList::new()
+    }
+
     /// Runs the GameType Object
     /// 
     /// # Arguments:
@@ -120,33 +136,35 @@ impl GameTypeData{
     /// 
     /// # Returns:
     /// 
-    /// The result of the request as a GameTypeResult object
-    /// 
-    /// If an error has occurred the program may return None or Error(ErrorType) as an OK
+    /// The gametype, or the [`Error`] if the request failed or the gametype could not be found
     /// 
     /// # Example:
-    /// ```rust
-    /// use speedrunapi::GameTypeData;
-    /// let result = GameTypeData::new("Fangame").run();
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, GameTypeData};
+    /// let client = Client::new();
+    /// let result = GameTypeData::new("Fangame").send(&client).await?;
     /// println!("{:?}", result);
+    /// # Ok(())
+    /// # }
     /// ```
     /// This will return the data from the gametype you are searching for
+    pub async fn send(&self, client: &Client) -> Result<GameType, Error>{
+        let path = format!("/gametypes/{:1}", self.gametype);
+        let response = client.get_json::<Data<GameType>>(&path).await?;
+        Ok(response.data)
+    }
 
-    #[tokio::main]
-    pub async fn run(&self) -> GameTypeResult{
-        let client = reqwest::Client::new();
-        let url = format!("https://www.speedrun.com/api/v1/gametypes/{:1}", self.gametype);
-        let response = match client.get(url).send().await{
-            Ok(response) => response,
-            Err(err) => return GameTypeResult::Error(GameTypeError::ReqwestError(err)),
-        };
-        if response.status() == reqwest::StatusCode::NOT_FOUND{
-            return GameTypeResult::Error(GameTypeError::GameTypeNotFound);
-        }
-        let response = match response.json::<Data>().await{
-            Ok(response) => response,
-            Err(err) => return GameTypeResult::Error(GameTypeError::ReqwestError(err)),
-        };
-        GameTypeResult::GameType(response)
+    Blocking_Function!(Result<GameType, Error>);
+}
+
+impl GameTypeList{
+
+    /// Sorts the list by name
+    pub fn order(mut self, direction: Direction) -> Self{
+        self.query.set("orderby", "name");
+        self.query.set("direction", direction.as_str());
+        self
     }
-}
\ No newline at end of file
+}
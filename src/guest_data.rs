@@ -1,41 +1,60 @@
 //! # Guests
-//! 
+//!
 //! Guests are users who havent yet logged into speedrun.com, so they have no account.
-//! 
-//! 
+//!
+//!
 //! ## Arguments
-//! Since guests can only have a name each of the function takes the argument name.
-//! 
+//! Since guests can only have a name the builder takes the argument name.
+//!
 //! name: &str, the name of the guest you are searching for.
-//! 
+//!
 //! ## Returns
-//! Any of the functions can panic if a 404 is returned.
-//! 
-//! If a 404 is not returned guest_data will return the users name and links inside of a vector as strings.
+//! If a 404 is returned the request returns [`Error::NotFound`](crate::Error::NotFound).
+//!
+//! If a 404 is not returned the guest is returned as a [`Guest`](crate::Guest), with its name and links.
+//!
+//! # Example:
+//!
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), speedrunapi::Error> {
+//! use speedrunapi::{Client, GuestData};
+//! let client = Client::new();
+//! let result = GuestData::new("Alex").send(&client).await?;
+//! println!("{}", result.name);
+//! # Ok(())
+//! # }
+//! ```
 
-use crate::types::GuestData;
+use crate::types::{Data, Guest};
+use crate::{Client, Error};
 
-#[tokio::main]
-async fn guest_reqwest(name: &str) -> Result<Vec<String>, reqwest::Error> {
-    let client = reqwest::Client::new();
-    let url = format!("https://www.speedrun.com/api/v1/guests/{:1}", name);
-    let response = client.get(url).send().await.unwrap();
-    if response.status() == reqwest::StatusCode::NOT_FOUND{
-        panic!("Guest Not Found!")
+#[derive(Debug)]
+pub struct GuestData{
+    pub name: String,
+}
+
+impl GuestData{
+
+    /// Creates a new GuestData object
+    ///
+    /// # Arguments:
+    ///
+    /// `name: &str` - The name of the guest you are searching for
+    pub fn new(name: &str) -> GuestData{
+        GuestData{
+            name: name.to_string(),
+        }
     }
-    let response = response.json::<GuestData>().await.unwrap();
-    
-    let guest = response.data;
-    let mut guest_data = Vec::<String>::new();
 
-    guest_data.extend([guest.name, guest.links.rel, guest.links.uri]);
-    Ok(guest_data)
-}
+    /// Returns all of the data for a guest
+    ///
+    /// If the guest cannot be found [`Error::NotFound`] is returned.
+    pub async fn send(&self, client: &Client) -> Result<Guest, Error>{
+        let path = format!("/guests/{:1}", self.name);
+        let response = client.get_json::<Data<Guest>>(&path).await?;
+        Ok(response.data)
+    }
 
-/// Returns all of the data for a guest
-/// 
-/// The data iis returned inside a vector contaiting strings.
-pub fn guest_data(name: &str) -> Vec<std::string::String>{
-    let guest_data = guest_reqwest(name).expect("guest Not found");
-    return guest_data;
-}
\ No newline at end of file
+    Blocking_Function!(Result<Guest, Error>);
+}
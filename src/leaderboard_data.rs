@@ -0,0 +1,183 @@
+//! Leaderboard data
+//! 
+//! This module provides the leaderboards of speedrun.com
+//! 
+//! A leaderboard is the ranked list of runs for one category of a game,
+//! or for one category of a level.
+//! 
+//! # Arguments:
+//! 
+//! Create the request with LeaderboardData::new("game", "category"), the game and category can be ids or abbreviations.
+//! For the leaderboard of a level add .level("level") before sending it.
+//! 
+//! The leaderboard can then be filtered, for example to only show the top 10 or only the runs on one platform.
+//! 
+//! # Examples:
+//! 
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), speedrunapi::Error> {
+//! use speedrunapi::{Client, LeaderboardData};
+//! let client = Client::new();
+//! let result = LeaderboardData::new("j1npme6p", "mkeyl926").top(3).send(&client).await?;
+//! for entry in result.runs{
+//!     println!("{}: {:?}", entry.place, entry.run.time());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//! This will print the top 3 places of the Any% Glitchless leaderboard of Minecraft: Java Edition.
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), speedrunapi::Error> {
+//! use speedrunapi::{Client, LeaderboardData};
+//! use speedrunapi::leaderboard_data::Timing;
+//! let client = Client::new();
+//! let result = LeaderboardData::new("j1npme6p", "mkeyl926")
+//!     .variable("jlzkwql2", "mln68v0q")
+//!     .timing(Timing::Ingame)
+//!     .video_only(true)
+//!     .send(&client)
+//!     .await?;
+//! println!("{:?}", result);
+//! # Ok(())
+//! # }
+//! ```
+//! This will fetch the leaderboard for one value of a variable, ranked by in game time and only with runs that have a video.
+
+use crate::query::Query;
+use crate::embed::LeaderboardEmbed;
+use crate::types::Data;
+use crate::types::Leaderboard;
+use crate::{Client, Error};
+
+#[derive(Debug, Clone)]
+pub struct LeaderboardData{
+    pub game: String,
+    pub category: String,
+    pub level: Option<String>,
+    query: Query,
+}
+
+/// The timing methods a leaderboard can be ranked by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Timing{
+    Realtime,
+    RealtimeNoLoads,
+    Ingame,
+}
+
+impl LeaderboardData{
+
+    /// Creates a new LeaderboardData object
+    /// 
+    /// # Arguments:
+    /// 
+    /// `game: &str` - The id or abbreviation of the game
+    /// 
+    /// `category: &str` - The id or name of the category
+    /// 
+    /// # Examples:
+    /// ```rust
+    /// use speedrunapi::LeaderboardData;
+    /// let result = LeaderboardData::new("j1npme6p", "mkeyl926");
+    /// println!("{:?}", result);
+    /// ```
+    pub fn new(game: &str, category: &str) -> LeaderboardData{
+        LeaderboardData{
+            game: game.to_string(),
+            category: category.to_string(),
+            level: None,
+            query: Query::default(),
+        }
+    }
+
+    /// Fetches the leaderboard of the category for a level instead of the full game
+    /// 
+    /// # Arguments:
+    /// 
+    /// `level: &str` - The id or name of the level
+    pub fn level(mut self, level: &str) -> Self{
+        self.level = Some(level.to_string());
+        self
+    }
+
+    /// Only returns the runs in the top places
+    /// 
+    /// Runs that share a place are all returned, so there can be more runs than `top`.
+    pub fn top(mut self, top: u32) -> Self{
+        self.query.set("top", &top.to_string());
+        self
+    }
+
+    Filter_Function!("Only returns the runs done on the platform with this id", platform, "platform");
+    Filter_Function!("Only returns the runs done in the region with this id", region, "region");
+    Filter_Function!("Returns the leaderboard as it was on this date, such as \"2023-01-01\"", date, "date");
+
+    /// Only returns the runs that were, or were not, done on an emulator
+    pub fn emulators(mut self, emulators: bool) -> Self{
+        self.query.set_bool("emulators", emulators);
+        self
+    }
+
+    /// Only returns the runs that have a video
+    pub fn video_only(mut self, video_only: bool) -> Self{
+        self.query.set_bool("video-only", video_only);
+        self
+    }
+
+    /// Ranks the runs by a timing method other than the default of the game
+    pub fn timing(mut self, timing: Timing) -> Self{
+        let timing = match timing{
+            Timing::Realtime => "realtime",
+            Timing::RealtimeNoLoads => "realtime_noloads",
+            Timing::Ingame => "ingame",
+        };
+        self.query.set("timing", timing);
+        self
+    }
+
+    /// Only returns the runs with this value for a variable
+    /// 
+    /// This is how subcategories are picked, it can be called once for every variable.
+    /// 
+    /// # Arguments:
+    /// 
+    /// `variable: &str` - The id of the variable
+    /// 
+    /// `value: &str` - The id of the value
+    pub fn variable(mut self, variable: &str, value: &str) -> Self{
+        self.query.set(&format!("var-{}", variable), value);
+        self
+    }
+
+    Embed_Function!(LeaderboardEmbed);
+
+    /// Runs the request to the speedrun.com API
+    /// 
+    /// # Returns:
+    /// 
+    /// The leaderboard, or the [`Error`] if the request failed or the leaderboard could not be found
+    /// 
+    /// # Example:
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, LeaderboardData};
+    /// let client = Client::new();
+    /// let result = LeaderboardData::new("j1npme6p", "mkeyl926").send(&client).await?;
+    /// println!("{:?}", result);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send(&self, client: &Client) -> Result<Leaderboard, Error>{
+        let path = match &self.level{
+            Some(level) => format!("/leaderboards/{}/level/{}/{}", self.game, level, self.category),
+            None => format!("/leaderboards/{}/category/{}", self.game, self.category),
+        };
+        let response = client.get_json::<Data<Leaderboard>>(&self.query.path(&path)).await?;
+        Ok(response.data)
+    }
+
+    Blocking_Function!(Result<Leaderboard, Error>);
+}
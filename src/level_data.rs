@@ -10,47 +10,62 @@
 //! 
 //! When calling for leveldata first you must Inintialize the builder, by calling LevelData,
 //! then to actutulay get it started you must provide ::new("level_name") suppling the builder with the level,
-//! and then call .run() to get the data.
+//! and then call .send(&client).await with a [`Client`](crate::Client) to get the data.
 //! If you choose to use an extension of the Level Data, call that before the run statment. 
 //! 
 //! # Examples:
 //! 
-//! ```rust
-//! use speedrunapi::LevelData;
-//! let result = LevelData::new("495ggmwp").run();
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), speedrunapi::Error> {
+//! use speedrunapi::{Client, LevelData};
+//! let client = Client::new();
+//! let result = LevelData::new("495ggmwp").send(&client).await?;
 //! println!("{:?}", result);
+//! # Ok(())
+//! # }
 //! ```
 //! This will fetch the entirenty of the data for the level as a json object and print it.
-//! ```rust
-//! use speedrunapi::LevelData;
-//! let result = LevelData::new("495ggmwp").run();
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), speedrunapi::Error> {
+//! use speedrunapi::{Client, LevelData};
+//! let client = Client::new();
+//! let result = LevelData::new("495ggmwp").send(&client).await?;
 //! println!("{:?}", result.name());
+//! # Ok(())
+//! # }
 //! ```
 //! This will fetch only the name of the level as a string and print it
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), speedrunapi::Error> {
+//! use speedrunapi::{Client, LevelData};
+//! let client = Client::new();
+//! let result = LevelData::new("495ggmwp").records().top(1).send(&client).await?;
+//! println!("{:?}", result);
+//! # Ok(())
+//! # }
+//! ```
+//! This will fetch the world record of every category of the level
 
-use crate::types::LevelData as Data;
+use crate::types::Data;
+use crate::query::Query;
+use crate::embed::{LevelEmbed, CategoryEmbed, LeaderboardEmbed};
+use crate::types::{Level, Category, Variable, Leaderboard};
+use crate::{Client, Error};
 
 #[derive(Debug)]
 pub struct LevelData{
     pub name: String,
-    categories: bool,
+    query: Query,
 }
 
-#[derive(Debug)]
-pub enum LevelError {
-    LevelNotFound,
-    InvalidArguments,
-    ReqwestError(reqwest::Error),
-}
+Sub_Resource!("A request for the categories of a level, created with [`LevelData::categories`]", LevelCategories, "levels", "categories", Category);
+Sub_Resource!("A request for the variables of a level, created with [`LevelData::variables`]", LevelVariables, "levels", "variables", Variable);
+Paged_Sub_Resource!("A request for the records of every category of a level, created with [`LevelData::records`]", LevelRecords, "levels", "records", Leaderboard);
 
-#[derive(Debug)]
-pub enum LevelResult {
-    Level(Data),
-    None,
-    Error(LevelError),
-}
-
-impl LevelResult {
+impl Level {
 
     /// Returns the name of the level
     /// 
@@ -59,19 +74,18 @@ impl LevelResult {
     /// The name of the level as an &str
     /// 
     /// ## Example:
-    /// ```rust
-    /// use speedrunapi::LevelData;
-    /// let result = LevelData::new("495ggmwp").run();
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, LevelData};
+    /// let client = Client::new();
+    /// let result = LevelData::new("495ggmwp").send(&client).await?;
     /// assert_eq!(result.name(), "Shrub Forest");
+    /// # Ok(())
+    /// # }
     /// ```
-
     pub fn name(&self) -> &str {
-        if let LevelResult::Level(level_data) = self {
-            &level_data.data.name
-        }
-        else{
-            panic!("Cannot get name from: {:?}", self);
-        }
+        &self.name
     }
 
     /// Returns the ID of a level
@@ -81,19 +95,18 @@ impl LevelResult {
     /// The ID of the level as an &str
     /// 
     /// ## Example:
-    /// ```rust
-    /// use speedrunapi::LevelData;
-    /// let result = LevelData::new("495ggmwp").run();
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, LevelData};
+    /// let client = Client::new();
+    /// let result = LevelData::new("495ggmwp").send(&client).await?;
     /// assert_eq!(result.id(), "495ggmwp");
+    /// # Ok(())
+    /// # }
     /// ```
-    
     pub fn id(&self) -> &str {
-        if let LevelResult::Level(level_data) = self {
-            &level_data.data.id
-        }
-        else{
-            panic!("Cannot get id from: {:?}", self);
-        }
+        &self.id
     }
 
     /// Returns the weblink of a level
@@ -107,46 +120,43 @@ impl LevelResult {
     /// The weblink is the url used to view the page on speedrun.com
     /// 
     /// ## Example:
-    /// ```rust
-    /// use speedrunapi::LevelData;
-    /// let result = LevelData::new("495ggmwp").run();
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, LevelData};
+    /// let client = Client::new();
+    /// let result = LevelData::new("495ggmwp").send(&client).await?;
     /// assert_eq!(result.weblink(), "https://www.speedrun.com/pokemon_rumble_world/Shrub_Forest");
+    /// # Ok(())
+    /// # }
     /// ```
-    
     pub fn weblink(&self) -> &str {
-        if let LevelResult::Level(level_data) = self {
-            &level_data.data.weblink
-        }
-        else{
-            panic!("Cannot get weblink from: {:?}", self);
-        }
+        &self.weblink
     }
 
     /// Returns the rules of a level
     /// 
     /// ## Returns:
     /// 
-    /// The rules of the level as a string
+    /// The rules of the level as an Option<String>, None if the level has no rules
     /// 
     /// *Note: 
     /// 
     /// The character string "\r\n\r\n" does have to be removed from the stirng in some spots. Be prepared if you are looking for them
     /// 
     /// ## Example:
-    /// ```rust
-    /// use speedrunapi::LevelData;
-    /// let result = LevelData::new("495ggmwp").run();
-    /// assert_eq!(result.rules(), "Normal Mode: Just go as fast as you can through the level. hard Mode: You must kill every Pokemon [Except the infinite re-spawning ones at the boss battle] Your time is the In game time.")
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, LevelData};
+    /// let client = Client::new();
+    /// let result = LevelData::new("495ggmwp").send(&client).await?;
+    /// assert_eq!(result.rules().as_deref(), Some("Normal Mode: Just go as fast as you can through the level. hard Mode: You must kill every Pokemon [Except the infinite re-spawning ones at the boss battle] Your time is the In game time."));
+    /// # Ok(())
+    /// # }
     /// ```
-
-    pub fn rules(&self) -> String {
-        if let LevelResult::Level(level_data) = self {
-            let rules = &level_data.data.rules;
-            rules.replace("\r\n\r\n", " ")
-        }
-        else{
-            panic!("Cannot get rules from: {:?}", self);
-        }
+    pub fn rules(&self) -> Option<String> {
+        self.rules.as_ref().map(|rules| rules.replace("\r\n\r\n", " "))
     }
 
     /// Returns the links associated with the level
@@ -156,31 +166,35 @@ impl LevelResult {
     /// A Vector of tuples contating the link name and the url to the link
     /// 
     /// ## Examples:
-    /// ```rust
-    /// use speedrunapi::LevelData;
-    /// let result = LevelData::new("495ggmwp").run();
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, LevelData};
+    /// let client = Client::new();
+    /// let result = LevelData::new("495ggmwp").send(&client).await?;
     /// println!("{:?}", result.links());
+    /// # Ok(())
+    /// # }
     /// ```
     /// This example prints out the entire links vector
-    /// ```rust
-    /// use speedrunapi::LevelData;
-    /// let result = LevelData::new("495ggmwp").run();
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, LevelData};
+    /// let client = Client::new();
+    /// let result = LevelData::new("495ggmwp").send(&client).await?;
     /// println!("{:?}", result.links()[0].0);
     /// assert_eq!(result.links()[0].0, "self");
+    /// # Ok(())
+    /// # }
     /// ```
     /// This prints out the first name of a link in the links vector 
-    
     pub fn links(&self) -> Vec<(String, String)> {
-        if let LevelResult::Level(level_data) = self {
-            let links = &level_data.data.links;
-            let names: Vec<&str> = links.iter().map(|link| link.rel.as_str()).collect();
-            let urls: Vec<&str> = links.iter().map(|link| link.uri.as_str()).collect();
-            let merged_vec: Vec<(String, String)> = names.iter().zip(urls.iter()).map(|(x, y)| (x.to_string(), y.to_string())).collect();
-            return merged_vec.to_vec();
-        }      
-        else{
-            panic!("Cannot get links from: {:?}", self);
-        }  
+        let links = &self.links;
+        let names: Vec<&str> = links.iter().map(|link| link.rel.as_str()).collect();
+        let urls: Vec<&str> = links.iter().map(|link| link.uri.as_str()).collect();
+        let merged_vec: Vec<(String, String)> = names.iter().zip(urls.iter()).map(|(x, y)| (x.to_string(), y.to_string())).collect();
+        merged_vec  
     }
 }
 
@@ -199,23 +213,72 @@ impl LevelData{
     /// println!("{:?}", result);
     /// ```
     /// This will return the parameters passed to the function, to return the data you will need to add run to the code.
-    /// ```rust
-    /// use speedrunapi::LevelData;
-    /// let result = LevelData::new("495ggmwp").run();
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, LevelData};
+    /// let client = Client::new();
+    /// let result = LevelData::new("495ggmwp").send(&client).await?;
     /// println!("{:?}", result);
+    /// # Ok(())
+    /// # }
     /// ```
-    
     pub fn new(name: &str) -> LevelData{
         LevelData{
             name: name.to_string(),
-            categories: false,
+            query: Query::default(),
         }
     }
 
-    /// Does nothing for now but will swich embed to get categories later
-    pub fn categories(mut self) -> Self{
-        self.categories = true;
-        self
+    /// Creates a request for the categories of the level
+    /// 
+    /// # Example:
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, LevelData};
+    /// let client = Client::new();
+    /// let result = LevelData::new("495ggmwp").categories().send(&client).await?;
+    /// println!("{:?}", result);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn categories(&self) -> LevelCategories{
+        LevelCategories::new(&self.name)
+    }
+
+    /// Creates a request for the variables of the level
+    /// 
+    /// # Example:
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, LevelData};
+    /// let client = Client::new();
+    /// let result = LevelData::new("495ggmwp").variables().send(&client).await?;
+    /// println!("{:?}", result);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn variables(&self) -> LevelVariables{
+        LevelVariables::new(&self.name)
+    }
+
+    /// Creates a request for the records of the level, one leaderboard for every category
+    /// 
+    /// # Example:
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, LevelData};
+    /// let client = Client::new();
+    /// let result = LevelData::new("495ggmwp").records().top(3).skip_empty(true).send(&client).await?;
+    /// println!("{:?}", result);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn records(&self) -> LevelRecords{
+        LevelRecords::new(&self.name)
     }
 
     /// Runs the request to the speedrun.com API
@@ -226,46 +289,55 @@ impl LevelData{
     /// 
     /// # Returns:
     /// 
-    /// The result of the request as a RunResult object
-    /// 
-    /// If an error has occurred the program may return None or Error(ErrorType) as an OK
+    /// The level, or the [`Error`] if the request failed or the level could not be found
     /// 
     /// # Example:
-    /// ```rust
-    /// use speedrunapi::LevelData;
-    /// let result = LevelData::new("495ggmwp").run();
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, LevelData};
+    /// let client = Client::new();
+    /// let result = LevelData::new("495ggmwp").send(&client).await?;
     /// println!("{:?}", result);
+    /// # Ok(())
+    /// # }
     /// ```
     /// This will return the data from the level you are searching for
-    
-    #[tokio::main]
-    pub async fn run(&self) -> LevelResult {
-        let client = reqwest::Client::new();
-        let url = if self.categories{
-            // fix url later
-            format!("https://www.speedrun.com/api/v1/levels/{}/categories", self.name)
-        } else{
-            format!("https://www.speedrun.com/api/v1/levels/{}", self.name)
-        };
-        let response = match client.get(url).send().await{
-            Ok(response) => response,
-            Err(err) => return LevelResult::Error(LevelError::ReqwestError(err.into())),
-        };
-        if response.status() == reqwest::StatusCode::NOT_FOUND{
-            return LevelResult::Error(LevelError::LevelNotFound);
-        }
-        if self.categories{
-            // for now print later on deseralize
-            println!("test");
-            LevelResult::None
-        }
-        else{
-            let response = match response.json::<Data>().await{
-                Ok(response) => response,
-                Err(err) => return LevelResult::Error(LevelError::ReqwestError(err.into())),
-            };
-            LevelResult::Level(response)
-        }
+    pub async fn send(&self, client: &Client) -> Result<Level, Error> {
+        let path = self.query.path(&format!("/levels/{}", self.name));
+        let response = client.get_json::<Data<Level>>(&path).await?;
+        Ok(response.data)
+    }
+
+    Embed_Function!(LevelEmbed);
+
+    Blocking_Function!(Result<Level, Error>);
+}
+
+impl LevelCategories{
+
+    /// Only returns the miscellaneous categories if true, or only the main categories if false
+    pub fn miscellaneous(mut self, miscellaneous: bool) -> Self{
+        self.query.set_bool("miscellaneous", miscellaneous);
+        self
+    }
+
+    Embed_Function!(CategoryEmbed);
+}
+
+impl LevelRecords{
+
+    /// Only returns the runs in the top places of each leaderboard
+    pub fn top(mut self, top: u32) -> Self{
+        self.query.set("top", &top.to_string());
+        self
+    }
+
+    /// Leaves out the leaderboards that have no runs if true
+    pub fn skip_empty(mut self, skip_empty: bool) -> Self{
+        self.query.set_bool("skip-empty", skip_empty);
+        self
     }
 
+    Embed_Function!(LeaderboardEmbed);
 }
@@ -7,16 +7,37 @@
 //! 
 //! - Pre-formatting of data 
 //! - Easy to use structure
-//! - Translations of ids/times (Not really used yet will be used more soon!)
-//! - Error handeling
+//! - Translations of ids/times, see [translate] (with the `translations` feature)
+//! - Error handeling, with one [`Error`] type for every request
+//! - Public [models](types) that can be cloned, compared and serialized
+//! - Pagination of list requests as a [stream](Paginator) that follows every page
+//! - Embedding of related resources into one request, see [embed]
+//! - Rate limiting, and retrying of requests that failed for a moment, see [client] and [retry]
+//! - An optional in memory [cache] of responses
 //! 
 //! ## Current Modules:
 //! 
+//! - [Client](#client)
 //! - [User Data](#user-data)
 //! - [Guest Data](#guest-data)
 //! - [Game Data](#game-data)
 //! - [Game Types](#game-types)
 //! - [Level Data](#level-data)
+//! - [Run Data](#run-data)
+//! - [Leaderboard Data](#leaderboard-data)
+//! - [Category Data](#category-data)
+//! - [Variable Data](#variable-data)
+//! - [Metadata](#metadata)
+//! - [Series Data](#series-data)
+//! 
+//! # Client
+//! Every request is sent through a [`Client`].
+//! 
+//! The client holds one connection pool, create it once and pass it to every request so the connections get reused.
+//! Requests are async and do not start their own runtime, so they can be used from inside any async context.
+//! 
+//! Every request is sent with `.send(&client).await`,
+//! if you don't want to write async code turn on the `blocking` feature and use `.run(&client)` instead.
 //! 
 //! # User Data
 //! This module provides data about users on speedrun.com.
@@ -28,10 +49,15 @@
 //! **Example:**
 //! 
 //! I am using user: Bobertness as an example here, to show off roles. **This is a real user!**
-//! ```rust
-//! use speedrunapi::UserData;
-//! let result = UserData::new("Bobertness").run();
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), speedrunapi::Error> {
+//! use speedrunapi::{Client, UserData};
+//! let client = Client::new();
+//! let result = UserData::new("Bobertness").send(&client).await?;
 //! assert_eq!(result.role(), "user");
+//! # Ok(())
+//! # }
 //! ```
 //! 
 //! # Guest Data
@@ -47,15 +73,21 @@
 //! Games are places on speedrun.com where users can submit runs for.
 //! 
 //! Games have many data points connected to them.
-//! There are a few more data points that go with a game, but require a new module to be used (in progress).
+//! The categories, levels, variables, derived games and records of a game are fetched with their own requests,
+//! and games can be searched for by name and other filters with [`GameData::list`].
 //! 
 //! **Example:**
 //! 
 //! This examples shows the fetching of the weblink of a game.
-//! ```rust
-//! use speedrunapi::GameData;
-//! let result = GameData::new("MC").run();
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), speedrunapi::Error> {
+//! use speedrunapi::{Client, GameData};
+//! let client = Client::new();
+//! let result = GameData::new("MC").send(&client).await?;
 //! assert_eq!(result.weblink(), "https://www.speedrun.com/mc");
+//! # Ok(())
+//! # }
 //! ```
 //! 
 //! # Game Types
@@ -69,19 +101,314 @@
 //! 
 //! # Levels
 //! 
+//! # Run Data
+//! This module provides data about runs on speedrun.com
+//! 
+//! A run can be fetched by its id with [`RunData::new`], or runs can be listed with [`RunData::list`]
+//! and filtered by user, guest, examiner, game, level, category, platform, region, emulator use and status.
+//! 
+//! # Leaderboard Data
+//! This module provides the leaderboards of speedrun.com
+//! 
+//! [`LeaderboardData`] fetches the ranked runs of a category, or of a category of a level,
+//! and can filter them the same way the website does, including by the values of variables.
+//! 
+//! # Category Data
+//! This module provides data about categories on speedrun.com
+//! 
+//! [`CategoryData`] fetches a category, with whether it is for the full game or for levels and how many players it is for,
+//! and its variables and records.
+//! 
+//! # Variable Data
+//! This module provides data about variables on speedrun.com
+//! 
+//! [`VariableData`] fetches a variable with its scope, its values and their labels,
+//! and whether it is a subcategory that splits leaderboards the way the website does.
+//! 
+//! # Metadata
+//! This module provides the platforms, regions, genres, engines, developers and publishers of games.
+//! 
+//! Games only list the ids of these, [`PlatformData`], [`RegionData`], [`GenreData`], [`EngineData`], [`DeveloperData`]
+//! and [`PublisherData`] look the ids up, or list every one of them. Gametypes can be listed with [`GameTypeData::list`].
+//! 
+//! # Series Data
+//! This module provides data about series on speedrun.com
+//! 
+//! [`SeriesData`] fetches a series of games, lists every series, and lists the games in a series with the same filters as every game.
+//! 
+//! # Migrating from 0.3
+//! 
+//! In 0.3 functions returned the text "None" (or \["None"\]) when speedrun.com had no data for a field,
+//! which could not be told apart from a game or user that really is called "None".
+//! Every optional field is now returned as an `Option`, so missing data is `None`:
+//! 
+//! | Function | 0.3 | Now |
+//! |---|---|---|
+//! | `User::japanese_name`, `pronouns`, `signup` | `String`, can be "None" | `Option<String>` |
+//! | `User::country`, `region` | `String`, can be "None" | `Option<String>` |
+//! | `User::links`, `assets` | `String`, can be "None" | `Option<String>` |
+//! | `Game::japanese_name`, `release_date`, `created`, `assets` | `String`, can be "None" | `Option<String>` |
+//! | `Level::rules` | `String` | `Option<String>` |
+//! | `Game::discord` | `String`, can be empty | `Option<String>` |
+//! | `Game::boosts`, `boosters` | `i32`, 0 when missing | `Option<i32>` |
+//! | `Game::gametypes`, `platforms`, `regions`, `genres`, `engines`, `developers`, `publishers` | `Vec<String>`, can be \["None"\] | `Option<Vec<String>>` |
+//! 
+//! If you want the old behaviour back add `unwrap_or` to the call:
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), speedrunapi::Error> {
+//! use speedrunapi::{Client, UserData};
+//! let client = Client::new();
+//! let user = UserData::new("fishin_rod").send(&client).await?;
+//! // 0.3: if user.pronouns() == "None" { ... }
+//! if user.pronouns().is_none(){
+//!     println!("No pronouns listed");
+//! }
+//! let pronouns = user.pronouns().unwrap_or(String::from("None"));
+//! # Ok(())
+//! # }
+//! ```
+//! 
 //! ---
 //! 
 //! *This crate is licensed under the MIT license
 
+/// Generates the blocking `run` function for a request, which waits on `send` using the shared runtime.
+macro_rules! Blocking_Function {
+    ($output:ty) => {
+        /// Runs the request to the speedrun.com API and waits for the result
+        ///
+        /// This does the same thing as `send` but can be called from code that is not async,
+        /// see the [blocking](crate::blocking) module for more information.
+        ///
+        /// ## Panics!
+        ///
+        /// This function will panic if it is called from inside an async context.
+        #[cfg(feature = "blocking")]
+        pub fn run(&self, client: &$crate::Client) -> $output {
+            $crate::blocking::block_on(self.send(client))
+        }
+    };
+}
+
+/// Generates the functions every list request has, which need a `path` function that returns the path with its query.
+///
+/// The list is split into pages by speedrun.com, `max` and `offset` pick the page `send` returns,
+/// `stream` returns every page one after another.
+macro_rules! Page_Functions {
+    ($item:ty) => {
+        /// Sets how many items are in each page, speedrun.com returns 20 by default and allows up to 200 (1000 in bulk mode)
+        pub fn max(mut self, max: u32) -> Self{
+            self.query.set("max", &max.to_string());
+            self
+        }
+
+        /// Skips this many items before the first one returned
+        pub fn offset(mut self, offset: u32) -> Self{
+            self.query.set("offset", &offset.to_string());
+            self
+        }
+
+        /// Runs the request to the speedrun.com API
+        ///
+        /// # Returns:
+        ///
+        /// One page of the list, or the [`Error`](crate::Error) if the request failed
+        pub async fn send(&self, client: &$crate::Client) -> Result<Vec<$item>, $crate::Error>{
+            let response = client.get_json::<$crate::types::Data<Vec<$item>>>(&self.path()).await?;
+            Ok(response.data)
+        }
+
+        Blocking_Function!(Result<Vec<$item>, $crate::Error>);
+
+        /// Returns every item in the list, fetching the pages one after another as they are needed
+        ///
+        /// See the [paginate](crate::paginate) module for more information.
+        pub fn stream(&self, client: &$crate::Client) -> $crate::Paginator<$item>{
+            $crate::Paginator::new(client, self.path())
+        }
+    };
+}
+
+/// Generates the `embed` function of a request, which adds to the resources embedded into what it returns.
+macro_rules! Embed_Function {
+    ($embed:ty) => {
+        /// Embeds other resources into the resources returned, see the [embed](crate::embed) module for more information
+        ///
+        /// Can be called more than once to embed more than one resource.
+        pub fn embed(mut self, embed: impl Into<$crate::embed::Embedding<$embed>>) -> Self{
+            self.query.add("embed", &embed.into().to_string());
+            self
+        }
+    };
+}
+
+/// Generates the request for a list that belongs to another resource, such as the categories of a game.
+///
+/// The request is created by a function on the parent request, so `new` is only public to the crate.
+/// speedrun.com returns these lists whole, use `Paged_Sub_Resource!` for the ones it splits into pages.
+macro_rules! Sub_Resource {
+    (@request $doc:expr, $name:ident, $parent:literal, $list:literal) => {
+        #[doc = $doc]
+        #[derive(Debug, Clone)]
+        pub struct $name{
+            pub id: String,
+            query: $crate::query::Query,
+        }
+
+        impl $name{
+            pub(crate) fn new(id: &str) -> $name{
+                $name{
+                    id: id.to_string(),
+                    query: $crate::query::Query::default(),
+                }
+            }
+
+            fn path(&self) -> String{
+                self.query.path(&format!(concat!("/", $parent, "/{}/", $list), self.id))
+            }
+        }
+    };
+    ($doc:expr, $name:ident, $parent:literal, $list:literal, $item:ty) => {
+        Sub_Resource!(@request $doc, $name, $parent, $list);
+
+        impl $name{
+            /// Runs the request to the speedrun.com API
+            ///
+            /// # Returns:
+            ///
+            /// The whole list, or the [`Error`](crate::Error) if the request failed
+            pub async fn send(&self, client: &$crate::Client) -> Result<Vec<$item>, $crate::Error>{
+                let response = client.get_json::<$crate::types::Data<Vec<$item>>>(&self.path()).await?;
+                Ok(response.data)
+            }
+
+            Blocking_Function!(Result<Vec<$item>, $crate::Error>);
+        }
+    };
+}
+
+/// Generates the request for a list that belongs to another resource and is split into pages, such as the records of a game.
+macro_rules! Paged_Sub_Resource {
+    ($doc:expr, $name:ident, $parent:literal, $list:literal, $item:ty) => {
+        Sub_Resource!(@request $doc, $name, $parent, $list);
+
+        impl $name{
+            Page_Functions!($item);
+        }
+    };
+}
+
+/// Generates a request for a list that is not part of another resource, such as every platform.
+macro_rules! List_Request {
+    ($doc:expr, $name:ident, $path:literal, $item:ty) => {
+        #[doc = $doc]
+        #[derive(Debug, Clone, Default)]
+        pub struct $name{
+            query: $crate::query::Query,
+        }
+
+        impl $name{
+            /// Creates the request without any filters
+            pub fn new() -> $name{
+                $name::default()
+            }
+
+            fn path(&self) -> String{
+                self.query.path(concat!("/", $path))
+            }
+
+            Page_Functions!($item);
+        }
+    };
+}
+
+/// Generates a setter for a list request that sets one filter of its query.
+macro_rules! Filter_Function {
+    ($doc:expr, $name:ident, $key:expr) => {
+        #[doc = $doc]
+        pub fn $name(mut self, $name: &str) -> Self {
+            self.query.set($key, $name);
+            self
+        }
+    };
+}
+
+/// Generates the filters speedrun.com has for lists of games, shared by every request that lists games.
+macro_rules! Game_Filters {
+    () => {
+        Filter_Function!("Only returns the games with a name like this one, the closest matches first", name, "name");
+        Filter_Function!("Only returns the game with this abbreviation", abbreviation, "abbreviation");
+        Filter_Function!("Only returns the games of the gametype with this id", gametype, "gametype");
+        Filter_Function!("Only returns the games on the platform with this id", platform, "platform");
+        Filter_Function!("Only returns the games released in the region with this id", region, "region");
+        Filter_Function!("Only returns the games of the genre with this id", genre, "genre");
+        Filter_Function!("Only returns the games made with the engine with this id", engine, "engine");
+        Filter_Function!("Only returns the games made by the developer with this id", developer, "developer");
+        Filter_Function!("Only returns the games published by the publisher with this id", publisher, "publisher");
+        Filter_Function!("Only returns the games moderated by the user with this id", moderator, "moderator");
+
+        /// Only returns the games released in this year
+        pub fn released(mut self, year: u32) -> Self{
+            self.query.set("released", &year.to_string());
+            self
+        }
+
+        /// Only returns romhacks if true, or leaves them out if false
+        pub fn romhack(mut self, romhack: bool) -> Self{
+            self.query.set_bool("romhack", romhack);
+            self
+        }
+
+        /// Sorts the games
+        ///
+        /// # Arguments:
+        ///
+        /// `order: GameOrder` - What to sort the games by
+        ///
+        /// `direction: Direction` - Which way to sort them
+        pub fn order(mut self, order: $crate::game_data::GameOrder, direction: $crate::Direction) -> Self{
+            self.query.set("orderby", order.as_str());
+            self.query.set("direction", direction.as_str());
+            self
+        }
+    };
+}
+
+pub mod client;
+pub use client::{Client, ClientBuilder};
+
+mod rate_limit;
+
+pub mod retry;
+pub use retry::RetryPolicy;
+
+pub mod cache;
+pub use cache::Cache;
+
+mod query;
+pub use query::Direction;
+
+pub mod error;
+pub use error::Error;
+
+pub mod paginate;
+pub use paginate::Paginator;
+
+pub mod embed;
+
+#[cfg(feature = "blocking")]
+pub mod blocking;
+
 #[cfg_attr(docsrs, doc(cfg(feature = "speedrunapi")))]
 pub mod user_data;
-pub use user_data::UserData;
+pub use user_data::{UserData, UserSearch};
 
 pub mod guest_data;
-pub use guest_data::{guest_data};
+pub use guest_data::GuestData;
 
 pub mod game_data;
-pub use game_data::GameData;
+pub use game_data::{GameData, GameSearch};
 
 pub mod gametype_data;
 pub use gametype_data::GameTypeData;
@@ -89,7 +416,26 @@ pub use gametype_data::GameTypeData;
 pub mod level_data;
 pub use level_data::LevelData;
 
+pub mod run_data;
+pub use run_data::{RunData, RunSearch};
+
+pub mod leaderboard_data;
+pub use leaderboard_data::LeaderboardData;
+
+pub mod category_data;
+pub use category_data::CategoryData;
+
+pub mod variable_data;
+pub use variable_data::VariableData;
+
+pub mod metadata;
+pub use metadata::{PlatformData, RegionData, GenreData, EngineData, DeveloperData, PublisherData};
+
+pub mod series_data;
+pub use series_data::{SeriesData, SeriesSearch};
+
 #[cfg(feature = "translations")]
 pub mod translate;
 
-pub(crate) mod types;
\ No newline at end of file
+pub mod types;
+pub use types::{User, Guest, Game, Level, GameType, Run, Leaderboard, Category, Variable, PersonalBest, Board, Series, Pagination, Link, ModeratorRole};
\ No newline at end of file
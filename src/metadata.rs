@@ -0,0 +1,116 @@
+//! Metadata
+//! 
+//! This module provides the metadata of games on speedrun.com
+//! 
+//! Games list their platforms, regions, genres, engines, developers and publishers as ids, such as "8gej2n93".
+//! The requests in this module look up what those ids are, or list every one speedrun.com has.
+//! 
+//! # Arguments:
+//! 
+//! A single item is fetched with its id by calling, for example, PlatformData::new("platform_id").
+//! 
+//! Every item is listed by calling, for example, PlatformData::list().
+//! The list is returned one page at a time, pick the page with .max() and .offset().
+//! 
+//! # Examples:
+//! 
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), speedrunapi::Error> {
+//! use speedrunapi::{Client, GameData, PlatformData};
+//! let client = Client::new();
+//! let game = GameData::new("mc").send(&client).await?;
+//! for id in game.platforms().unwrap_or_default(){
+//!     let platform = PlatformData::new(&id).send(&client).await?;
+//!     println!("{}", platform.name);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//! This will print the names of the platforms of Minecraft: Java Edition.
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), speedrunapi::Error> {
+//! use speedrunapi::{Client, Direction, GenreData};
+//! let client = Client::new();
+//! let result = GenreData::list().order(Direction::Ascending).max(200).send(&client).await?;
+//! println!("{:?}", result);
+//! # Ok(())
+//! # }
+//! ```
+//! This will print the first 200 genres sorted by name.
+
+use crate::types::{Platform, Region, Genre, Engine, Developer, Publisher};
+use crate::Direction;
+
+/// Generates the lookup and list requests for one kind of metadata
+macro_rules! Metadata_Request {
+    ($kind:literal, $name:ident, $list:ident, $path:literal, $item:ty) => {
+        #[doc = concat!("A request for one ", $kind, " by its id")]
+        #[derive(Debug)]
+        pub struct $name{
+            pub id: String,
+        }
+
+        List_Request!(concat!("A request for every ", $kind, ", created with [`", stringify!($name), "::list`]"), $list, $path, $item);
+
+        impl $name{
+
+            #[doc = concat!("Creates a new ", stringify!($name), " object")]
+            ///
+            /// # Arguments:
+            ///
+            #[doc = concat!("`id: &str` - The id of the ", $kind)]
+            pub fn new(id: &str) -> $name{
+                $name{
+                    id: id.to_string(),
+                }
+            }
+
+            #[doc = concat!("Creates a request for every ", $kind)]
+            pub fn list() -> $list{
+                $list::new()
+            }
+
+            /// Runs the request to the speedrun.com API
+            ///
+            /// # Returns:
+            ///
+            #[doc = concat!("The ", $kind, ", or the [`Error`](crate::Error) if the request failed or it could not be found")]
+            pub async fn send(&self, client: &$crate::Client) -> Result<$item, $crate::Error>{
+                let path = format!(concat!("/", $path, "/{}"), self.id);
+                let response = client.get_json::<$crate::types::Data<$item>>(&path).await?;
+                Ok(response.data)
+            }
+
+            Blocking_Function!(Result<$item, $crate::Error>);
+        }
+
+        impl $list{
+
+            /// Sorts the list by name
+            pub fn order(mut self, direction: Direction) -> Self{
+                self.query.set("orderby", "name");
+                self.query.set("direction", direction.as_str());
+                self
+            }
+        }
+    };
+}
+
+Metadata_Request!("platform", PlatformData, PlatformList, "platforms", Platform);
+Metadata_Request!("region", RegionData, RegionList, "regions", Region);
+Metadata_Request!("genre", GenreData, GenreList, "genres", Genre);
+Metadata_Request!("engine", EngineData, EngineList, "engines", Engine);
+Metadata_Request!("developer", DeveloperData, DeveloperList, "developers", Developer);
+Metadata_Request!("publisher", PublisherData, PublisherList, "publishers", Publisher);
+
+impl PlatformList{
+
+    /// Sorts the list by the year the platforms were released
+    pub fn order_released(mut self, direction: Direction) -> Self{
+        self.query.set("orderby", "released");
+        self.query.set("direction", direction.as_str());
+        self
+    }
+}
@@ -0,0 +1,120 @@
+//! # Pagination
+//! 
+//! speedrun.com splits long lists, such as every run of a game, into pages.
+//! 
+//! `send` on a list request only returns one page. `stream` returns a [`Paginator`] instead,
+//! which is a [`Stream`] of every item in the list that fetches the next page by itself when it runs out,
+//! following the `next` link speedrun.com sends with each page.
+//! 
+//! The size of each page is set with `max` on the request and the total number of items with [`Paginator::limit`].
+//! Lists speedrun.com always returns whole, such as the categories of a game, only have `send`.
+//! 
+//! # Example:
+//! 
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), speedrunapi::Error> {
+//! use futures_util::StreamExt;
+//! use speedrunapi::{Client, RunData};
+//! let client = Client::new();
+//! let mut runs = RunData::list().game("j1npme6p").max(200).stream(&client).limit(1000);
+//! while let Some(run) = runs.next().await{
+//!     println!("{:?}", run?.time());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//! This will print the times of the first 1000 runs of Minecraft: Java Edition, fetching 200 at a time.
+
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_util::stream::{self, Stream, StreamExt};
+use serde::de::DeserializeOwned;
+
+use crate::types::Page;
+use crate::{Client, Error};
+
+/// A stream of every item in a list, created with `stream` on a list request
+/// 
+/// Each item is a `Result`, if a page cannot be fetched the error is returned and the stream ends.
+pub struct Paginator<T>{
+    inner: Pin<Box<dyn Stream<Item = Result<T, Error>> + Send>>,
+}
+
+/// Where the paginator is in the list
+struct State<T>{
+    client: Client,
+    next: Option<String>,
+    buffer: VecDeque<T>,
+}
+
+impl<T: DeserializeOwned + Send + 'static> Paginator<T>{
+
+    /// Creates a paginator that starts with the page at the path
+    pub(crate) fn new(client: &Client, path: String) -> Paginator<T>{
+        let state = State{
+            client: client.clone(),
+            next: Some(path),
+            buffer: VecDeque::new(),
+        };
+        let inner = stream::unfold(state, |mut state| async move {
+            loop{
+                if let Some(item) = state.buffer.pop_front(){
+                    return Some((Ok(item), state));
+                }
+                let path = state.next.take()?;
+                match state.client.get_json::<Page<T>>(&path).await{
+                    Ok(page) => {
+                        state.next = page.next().map(|link| state.client.relative(link).to_string());
+                        state.buffer.extend(page.data);
+                    }
+                    Err(err) => return Some((Err(err), state)),
+                }
+            }
+        });
+        Paginator{
+            inner: Box::pin(inner),
+        }
+    }
+
+    /// Stops the stream after this many items, no more pages are fetched once it is reached
+    /// 
+    /// # Arguments:
+    /// 
+    /// `limit: usize` - The most items the stream returns
+    pub fn limit(self, limit: usize) -> Paginator<T>{
+        Paginator{
+            inner: Box::pin(self.inner.take(limit)),
+        }
+    }
+
+    /// Collects every item of the stream into a vector
+    /// 
+    /// # Returns:
+    /// 
+    /// Every item, or the first [`Error`] returned while fetching the pages
+    pub async fn collect_all(self) -> Result<Vec<T>, Error>{
+        let mut items = Vec::new();
+        let mut stream = self;
+        while let Some(item) = stream.next().await{
+            items.push(item?);
+        }
+        Ok(items)
+    }
+}
+
+impl<T> Stream for Paginator<T>{
+    type Item = Result<T, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>>{
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+impl<T> std::fmt::Debug for Paginator<T>{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result{
+        f.debug_struct("Paginator").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,71 @@
+//! Query strings for the list requests
+//! 
+//! List requests store their filters as key value pairs,
+//! which are encoded and added to the end of the path when the request is sent.
+
+/// Which way a list should be sorted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction{
+    Ascending,
+    Descending,
+}
+
+impl Direction{
+    pub(crate) fn as_str(&self) -> &'static str{
+        match self{
+            Direction::Ascending => "asc",
+            Direction::Descending => "desc",
+        }
+    }
+}
+
+/// The filters of a list request in the order they were set
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct Query{
+    pairs: Vec<(String, String)>,
+}
+
+impl Query{
+
+    /// Sets the value for a key, replacing the value if the key was already set
+    pub(crate) fn set(&mut self, key: &str, value: &str){
+        match self.pairs.iter_mut().find(|(k, _)| k == key){
+            Some(pair) => pair.1 = value.to_string(),
+            None => self.pairs.push((key.to_string(), value.to_string())),
+        }
+    }
+
+    /// Sets a yes or no filter, which is encoded the same way for every request
+    pub(crate) fn set_bool(&mut self, key: &str, value: bool){
+        self.set(key, if value { "yes" } else { "no" });
+    }
+
+    /// Adds a value to a key that takes a comma separated list, such as embed
+    pub(crate) fn add(&mut self, key: &str, value: &str){
+        match self.pairs.iter_mut().find(|(k, _)| k == key){
+            Some(pair) => pair.1 = format!("{},{}", pair.1, value),
+            None => self.pairs.push((key.to_string(), value.to_string())),
+        }
+    }
+
+    /// Returns the path with the encoded query added to the end, or the path alone if nothing was set
+    pub(crate) fn path(&self, path: &str) -> String{
+        if self.pairs.is_empty(){
+            return path.to_string();
+        }
+        let query: Vec<String> = self.pairs.iter().map(|(key, value)| format!("{}={}", encode(key), encode(value))).collect();
+        format!("{}?{}", path, query.join("&"))
+    }
+}
+
+/// Percent encodes everything apart from the characters urls leave as they are
+fn encode(text: &str) -> String{
+    let mut encoded = String::new();
+    for byte in text.bytes(){
+        match byte{
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
@@ -0,0 +1,86 @@
+//! Rate limiting
+//!
+//! speedrun.com allows 100 requests a minute and answers with the status 420 when it gets more.
+//! The client waits before sending a request that would go over the limit, using a token bucket:
+//! the bucket holds a token for every request allowed in the period and refills at the same speed,
+//! so a short burst is sent at once while the average stays under the limit.
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+/// A token bucket shared by every clone of a client
+#[derive(Debug)]
+pub(crate) struct RateLimiter{
+    capacity: f64,
+    per_token: Duration,
+    period: Duration,
+    bucket: Mutex<Bucket>,
+}
+
+#[derive(Debug)]
+struct Bucket{
+    /// The tokens left, below zero when requests are waiting for tokens that are not there yet
+    tokens: f64,
+    /// When the tokens were last counted, in the future while speedrun.com asked us to wait
+    updated: Instant,
+}
+
+impl RateLimiter{
+
+    /// Creates a full bucket that allows the requests in the period
+    pub(crate) fn new(requests: u32, period: Duration) -> RateLimiter{
+        let requests = requests.max(1);
+        RateLimiter{
+            capacity: requests as f64,
+            per_token: period / requests,
+            period,
+            bucket: Mutex::new(Bucket{
+                tokens: requests as f64,
+                updated: Instant::now(),
+            }),
+        }
+    }
+
+    /// Returns how long the limit is counted over
+    pub(crate) fn period(&self) -> Duration{
+        self.period
+    }
+
+    /// Takes a token, waiting until there is one
+    ///
+    /// The token is taken before waiting, so requests that wait are sent in the order they asked.
+    pub(crate) async fn acquire(&self){
+        let wait = {
+            let mut bucket = self.bucket.lock().unwrap_or_else(|err| err.into_inner());
+            let now = Instant::now();
+            self.refill(&mut bucket, now);
+            bucket.tokens -= 1.0;
+            let missing = self.per_token.mul_f64((-bucket.tokens).max(0.0));
+            bucket.updated.saturating_duration_since(now) + missing
+        };
+        if !wait.is_zero(){
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Empties the bucket and stops it refilling for the delay, after speedrun.com rate limited a request anyway
+    pub(crate) fn pause(&self, delay: Duration){
+        let mut bucket = self.bucket.lock().unwrap_or_else(|err| err.into_inner());
+        let now = Instant::now();
+        self.refill(&mut bucket, now);
+        bucket.tokens = bucket.tokens.min(0.0);
+        bucket.updated = bucket.updated.max(now + delay);
+    }
+
+    /// Adds the tokens that came back since the bucket was last counted
+    fn refill(&self, bucket: &mut Bucket, now: Instant){
+        if now <= bucket.updated{
+            return;
+        }
+        let refilled = now.duration_since(bucket.updated).as_secs_f64() / self.per_token.as_secs_f64();
+        bucket.tokens = (bucket.tokens + refilled).min(self.capacity);
+        bucket.updated = now;
+    }
+}
@@ -0,0 +1,172 @@
+//! Retrying
+//!
+//! speedrun.com often fails for a moment, with a 5xx status, a dropped connection or a rate limit.
+//! A client with a [`RetryPolicy`] sends a request that failed like that again after a delay,
+//! which doubles after every attempt, so jobs that send lots of requests don't fail because of one hiccup.
+//!
+//! If speedrun.com sends a `Retry-After` header the request waits that long instead,
+//! the header can be a number of seconds or an HTTP date.
+//! When it asks for a longer wait than the policys `max_delay` the request is not retried,
+//! the [`Error::RateLimited`](crate::Error::RateLimited) is returned with the delay so the caller can pick what to do.
+//!
+//! Clients don't retry unless they are built with a policy.
+//!
+//! # Example:
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use speedrunapi::{Client, RetryPolicy};
+//! let client = Client::builder()
+//!     .retry(RetryPolicy::new().max_attempts(5).base_delay(Duration::from_secs(1)))
+//!     .build()
+//!     .unwrap();
+//! ```
+//! This will create a client that sends every request up to 5 times, waiting about 1, 2, 4 and then 8 seconds in between.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use crate::Error;
+
+/// When and how often a client sends a failed request again
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy{
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    statuses: Vec<u16>,
+    jitter: bool,
+}
+
+impl RetryPolicy{
+
+    /// Creates a policy that sends a request up to 3 times, waiting 500ms and then 1s in between
+    ///
+    /// The statuses retried are 420 and 429, which speedrun.com uses for rate limits, and 500, 502, 503 and 504.
+    /// Requests that could not be sent or whose response could not be read are retried too.
+    pub fn new() -> RetryPolicy{
+        RetryPolicy{
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            statuses: vec![420, 429, 500, 502, 503, 504],
+            jitter: true,
+        }
+    }
+
+    /// Creates a policy that sends every request once, which is what a client does without a policy
+    pub fn none() -> RetryPolicy{
+        RetryPolicy::new().max_attempts(1)
+    }
+
+    /// Sets how many times a request is sent, counting the first time
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self{
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Sets the delay before the second attempt, each attempt after it waits twice as long as the one before
+    pub fn base_delay(mut self, base_delay: Duration) -> Self{
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Sets the longest delay between two attempts
+    ///
+    /// If speedrun.com asks for a longer delay with `Retry-After`, the error is returned instead of waiting.
+    pub fn max_delay(mut self, max_delay: Duration) -> Self{
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Sets the statuses that are retried, replacing the default ones
+    pub fn statuses(mut self, statuses: &[u16]) -> Self{
+        self.statuses = statuses.to_vec();
+        self
+    }
+
+    /// Picks if the delays are randomized to between half and all of their length, which is on by default
+    ///
+    /// This stops lots of clients that failed at the same time from all retrying at the same time too.
+    pub fn jitter(mut self, jitter: bool) -> Self{
+        self.jitter = jitter;
+        self
+    }
+
+    /// Returns how long to wait before sending the request again, or None if the error should be returned
+    ///
+    /// The error is returned without waiting if `Retry-After` asks for a longer delay than `max_delay`.
+    ///
+    /// # Arguments:
+    ///
+    /// `attempt` - How many times the request has been sent
+    ///
+    /// `failure` - Why the last attempt failed
+    pub(crate) fn delay(&self, attempt: u32, failure: &Failure) -> Option<Duration>{
+        if attempt >= self.max_attempts || !self.retries(failure){
+            return None;
+        }
+        if let Some(retry_after) = failure.retry_after{
+            return (retry_after <= self.max_delay).then_some(retry_after);
+        }
+        let delay = self.base_delay.saturating_mul(2u32.saturating_pow(attempt - 1)).min(self.max_delay);
+        if self.jitter{
+            return Some(delay.mul_f64(0.5 + random() / 2.0));
+        }
+        Some(delay)
+    }
+
+    /// Returns true if the failure may go away if the request is sent again
+    fn retries(&self, failure: &Failure) -> bool{
+        match (failure.status, &failure.error){
+            (Some(status), _) => self.statuses.contains(&status),
+            (None, Error::Transport(err)) => err.is_connect() || err.is_timeout() || err.is_request() || err.is_body(),
+            _ => false,
+        }
+    }
+}
+
+impl Default for RetryPolicy{
+    fn default() -> Self{
+        RetryPolicy::new()
+    }
+}
+
+/// A request that failed, with what the response said about sending it again
+#[derive(Debug)]
+pub(crate) struct Failure{
+    pub(crate) error: Error,
+    /// The status of the response, None if there was no response
+    pub(crate) status: Option<u16>,
+    /// The delay speedrun.com asked for with `Retry-After`
+    pub(crate) retry_after: Option<Duration>,
+}
+
+impl From<Error> for Failure{
+    fn from(error: Error) -> Self{
+        Failure{
+            error,
+            status: None,
+            retry_after: None,
+        }
+    }
+}
+
+/// Reads a `Retry-After` header, which is either a number of seconds or an HTTP date such as `Wed, 21 Oct 2015 07:28:00 GMT`
+///
+/// A date in the past is no delay at all.
+pub(crate) fn retry_after(value: &str) -> Option<Duration>{
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>(){
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    Some((date.with_timezone(&chrono::Utc) - chrono::Utc::now()).to_std().unwrap_or_default())
+}
+
+/// Returns a random number from 0 up to 1, from the random keys the standard library makes for hash maps
+fn random() -> f64{
+    let random = RandomState::new().build_hasher().finish();
+    (random >> 11) as f64 / (1u64 << 53) as f64
+}
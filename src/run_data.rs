@@ -0,0 +1,254 @@
+//! Run data
+//! 
+//! This module provides data about runs on speedrun.com
+//! 
+//! Runs are the times people submit for a category of a game.
+//! 
+//! # Arguments:
+//! 
+//! A single run is fetched with its id by calling RunData::new("run_id").
+//! 
+//! To list runs call RunData::list(), add the filters you want, and call .send(&client).await with a [`Client`](crate::Client).
+//! The filters take the ids of users, games, categories, etc. not their names.
+//! 
+//! # Examples:
+//! 
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), speedrunapi::Error> {
+//! use speedrunapi::{Client, RunData};
+//! let client = Client::new();
+//! let result = RunData::new("90y6pm7e").send(&client).await?;
+//! println!("{:?}", result.time());
+//! # Ok(())
+//! # }
+//! ```
+//! This will fetch a run and print its time.
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), speedrunapi::Error> {
+//! use speedrunapi::{Client, Direction, RunData};
+//! use speedrunapi::run_data::{RunOrder, Status};
+//! let client = Client::new();
+//! let result = RunData::list()
+//!     .game("j1npme6p")
+//!     .status(Status::Verified)
+//!     .order(RunOrder::Submitted, Direction::Descending)
+//!     .send(&client)
+//!     .await?;
+//! println!("{:?}", result);
+//! # Ok(())
+//! # }
+//! ```
+//! This will fetch the newest verified runs of Minecraft: Java Edition.
+
+use std::time::Duration;
+
+use crate::types::Data;
+use crate::query::Query;
+use crate::embed::RunEmbed;
+use crate::types::{Run, RunStatus};
+use crate::{Client, Direction, Error};
+
+#[derive(Debug)]
+pub struct RunData{
+    pub id: String,
+    query: Query,
+}
+
+List_Request!("A request for a list of runs, created with [`RunData::list`]", RunSearch, "runs", Run);
+
+/// The status a run can be filtered by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status{
+    New,
+    Verified,
+    Rejected,
+}
+
+/// What a list of runs can be sorted by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunOrder{
+    Game,
+    Category,
+    Level,
+    Platform,
+    Region,
+    Emulated,
+    Date,
+    Submitted,
+    Status,
+    VerifyDate,
+}
+
+impl Run{
+
+    /// Returns the primary time of the run
+    /// 
+    /// ## Returns:
+    /// 
+    /// The time as a Duration
+    /// 
+    /// ## Example:
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, RunData};
+    /// let client = Client::new();
+    /// let result = RunData::new("90y6pm7e").send(&client).await?;
+    /// println!("{:?}", result.time());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn time(&self) -> Duration{
+        Duration::from_secs_f64(self.times.primary_t)
+    }
+
+    /// Returns the links to the videos of the run
+    /// 
+    /// ## Returns:
+    /// 
+    /// The links as a Vec<String>, empty if the run has no videos
+    /// 
+    /// ## Example:
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, RunData};
+    /// let client = Client::new();
+    /// let result = RunData::new("90y6pm7e").send(&client).await?;
+    /// println!("{:?}", result.video_links());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn video_links(&self) -> Vec<String>{
+        self.videos.as_ref()
+            .and_then(|videos| videos.links.as_ref())
+            .map(|links| links.iter().map(|link| link.uri.clone()).collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns true if the run has been verified by a moderator
+    pub fn is_verified(&self) -> bool{
+        matches!(self.status, RunStatus::Verified{ .. })
+    }
+}
+
+impl RunData{
+
+    /// Creates a new RunData object
+    /// 
+    /// # Arguments:
+    /// 
+    /// `id: &str` - The id of the run
+    /// 
+    /// # Examples:
+    /// ```rust
+    /// use speedrunapi::RunData;
+    /// let result = RunData::new("90y6pm7e");
+    /// println!("{:?}", result);
+    /// ```
+    pub fn new(id: &str) -> RunData{
+        RunData{
+            id: id.to_string(),
+            query: Query::default(),
+        }
+    }
+
+    /// Creates a request for a list of runs
+    /// 
+    /// Without any filters speedrun.com returns every run, so you will want to add some.
+    /// 
+    /// # Examples:
+    /// ```rust
+    /// use speedrunapi::RunData;
+    /// let result = RunData::list().user("jonryvl8").emulated(false);
+    /// println!("{:?}", result);
+    /// ```
+    pub fn list() -> RunSearch{
+        RunSearch::new()
+    }
+
+    /// Runs the request to the speedrun.com API
+    /// 
+    /// # Returns:
+    /// 
+    /// The run, or the [`Error`] if the request failed or the run could not be found
+    /// 
+    /// # Example:
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, RunData};
+    /// let client = Client::new();
+    /// let result = RunData::new("90y6pm7e").send(&client).await?;
+    /// println!("{:?}", result);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send(&self, client: &Client) -> Result<Run, Error>{
+        let path = self.query.path(&format!("/runs/{}", self.id));
+        let response = client.get_json::<Data<Run>>(&path).await?;
+        Ok(response.data)
+    }
+
+    Embed_Function!(RunEmbed);
+
+    Blocking_Function!(Result<Run, Error>);
+}
+
+impl RunSearch{
+
+    Filter_Function!("Only returns the runs done by the user with this id", user, "user");
+    Filter_Function!("Only returns the runs done by the guest with this name", guest, "guest");
+    Filter_Function!("Only returns the runs checked by the moderator with this id", examiner, "examiner");
+    Filter_Function!("Only returns the runs of the game with this id", game, "game");
+    Filter_Function!("Only returns the runs of the level with this id", level, "level");
+    Filter_Function!("Only returns the runs of the category with this id", category, "category");
+    Filter_Function!("Only returns the runs done on the platform with this id", platform, "platform");
+    Filter_Function!("Only returns the runs done in the region with this id", region, "region");
+
+    /// Only returns the runs that were, or were not, done on an emulator
+    pub fn emulated(mut self, emulated: bool) -> Self{
+        self.query.set_bool("emulated", emulated);
+        self
+    }
+
+    /// Only returns the runs with this status
+    pub fn status(mut self, status: Status) -> Self{
+        let status = match status{
+            Status::New => "new",
+            Status::Verified => "verified",
+            Status::Rejected => "rejected",
+        };
+        self.query.set("status", status);
+        self
+    }
+
+    /// Sorts the runs
+    /// 
+    /// # Arguments:
+    /// 
+    /// `order: RunOrder` - What to sort the runs by
+    /// 
+    /// `direction: Direction` - Which way to sort them
+    pub fn order(mut self, order: RunOrder, direction: Direction) -> Self{
+        let order = match order{
+            RunOrder::Game => "game",
+            RunOrder::Category => "category",
+            RunOrder::Level => "level",
+            RunOrder::Platform => "platform",
+            RunOrder::Region => "region",
+            RunOrder::Emulated => "emulated",
+            RunOrder::Date => "date",
+            RunOrder::Submitted => "submitted",
+            RunOrder::Status => "status",
+            RunOrder::VerifyDate => "verify-date",
+        };
+        self.query.set("orderby", order);
+        self.query.set("direction", direction.as_str());
+        self
+    }
+
+    Embed_Function!(RunEmbed);
+}
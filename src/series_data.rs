@@ -0,0 +1,180 @@
+//! Series
+//! 
+//! This module provides data about series on speedrun.com
+//! 
+//! A series is a group of games, such as every Mario game, with moderators of its own.
+//! 
+//! # Arguments:
+//! 
+//! A single series is fetched with its id or abbreviation by calling SeriesData::new("series").
+//! Every series is listed with SeriesData::list(), which can be filtered by name, abbreviation and moderator.
+//! 
+//! The games of a series are fetched with SeriesData::new("series").games(),
+//! which has the same filters as listing every game.
+//! 
+//! # Examples:
+//! 
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), speedrunapi::Error> {
+//! use speedrunapi::{Client, SeriesData};
+//! let client = Client::new();
+//! let result = SeriesData::new("mario").send(&client).await?;
+//! println!("{}", result.names.international);
+//! # Ok(())
+//! # }
+//! ```
+//! This will fetch the Mario series and print its name.
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), speedrunapi::Error> {
+//! use speedrunapi::{Client, Direction, SeriesData};
+//! use speedrunapi::game_data::GameOrder;
+//! let client = Client::new();
+//! let result = SeriesData::new("mario")
+//!     .games()
+//!     .romhack(false)
+//!     .order(GameOrder::Released, Direction::Ascending)
+//!     .send(&client)
+//!     .await?;
+//! for game in result{
+//!     println!("{}", game.name());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//! This will print every game in the Mario series that is not a romhack, oldest first.
+
+use crate::types::Data;
+use crate::query::Query;
+use crate::embed::{SeriesEmbed, GameEmbed};
+use crate::types::{Series, Game};
+use crate::{Client, Direction, Error};
+
+#[derive(Debug)]
+pub struct SeriesData{
+    pub id: String,
+    query: Query,
+}
+
+List_Request!("A request for a list of series, created with [`SeriesData::list`]", SeriesSearch, "series", Series);
+Paged_Sub_Resource!("A request for the games of a series, created with [`SeriesData::games`]", SeriesGames, "series", "games", Game);
+
+/// What a list of series can be sorted by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeriesOrder{
+    /// The english name
+    Name,
+    /// The japanese name
+    JapaneseName,
+    Abbreviation,
+    /// When the series was added to speedrun.com
+    Created,
+}
+
+impl SeriesData{
+
+    /// Creates a new SeriesData object
+    /// 
+    /// # Arguments:
+    /// 
+    /// `id: &str` - The id or abbreviation of the series
+    /// 
+    /// # Examples:
+    /// ```rust
+    /// use speedrunapi::SeriesData;
+    /// let result = SeriesData::new("mario");
+    /// println!("{:?}", result);
+    /// ```
+    pub fn new(id: &str) -> SeriesData{
+        SeriesData{
+            id: id.to_string(),
+            query: Query::default(),
+        }
+    }
+
+    /// Creates a request for a list of series
+    /// 
+    /// # Examples:
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, SeriesData};
+    /// let client = Client::new();
+    /// let result = SeriesData::list().name("mario").send(&client).await?;
+    /// println!("{:?}", result);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list() -> SeriesSearch{
+        SeriesSearch::new()
+    }
+
+    /// Creates a request for the games of the series
+    /// 
+    /// The games can be filtered the same way as [`GameSearch`](crate::GameSearch)
+    pub fn games(&self) -> SeriesGames{
+        SeriesGames::new(&self.id)
+    }
+
+    /// Runs the request to the speedrun.com API
+    /// 
+    /// # Returns:
+    /// 
+    /// The series, or the [`Error`] if the request failed or the series could not be found
+    /// 
+    /// # Example:
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, SeriesData};
+    /// let client = Client::new();
+    /// let result = SeriesData::new("mario").send(&client).await?;
+    /// println!("{:?}", result);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send(&self, client: &Client) -> Result<Series, Error>{
+        let path = self.query.path(&format!("/series/{}", self.id));
+        let response = client.get_json::<Data<Series>>(&path).await?;
+        Ok(response.data)
+    }
+
+    Embed_Function!(SeriesEmbed);
+
+    Blocking_Function!(Result<Series, Error>);
+}
+
+impl SeriesSearch{
+
+    Filter_Function!("Only returns the series with a name like this one", name, "name");
+    Filter_Function!("Only returns the series with this abbreviation", abbreviation, "abbreviation");
+    Filter_Function!("Only returns the series moderated by the user with this id", moderator, "moderator");
+
+    /// Sorts the series
+    /// 
+    /// # Arguments:
+    /// 
+    /// `order: SeriesOrder` - What to sort the series by
+    /// 
+    /// `direction: Direction` - Which way to sort them
+    pub fn order(mut self, order: SeriesOrder, direction: Direction) -> Self{
+        let order = match order{
+            SeriesOrder::Name => "name.int",
+            SeriesOrder::JapaneseName => "name.jap",
+            SeriesOrder::Abbreviation => "abbreviation",
+            SeriesOrder::Created => "created",
+        };
+        self.query.set("orderby", order);
+        self.query.set("direction", direction.as_str());
+        self
+    }
+
+    Embed_Function!(SeriesEmbed);
+}
+
+impl SeriesGames{
+    Game_Filters!();
+
+    Embed_Function!(GameEmbed);
+}
@@ -1,6 +1,220 @@
 //! # Translations
 //! 
 //! This file is for the translations and conversions of certin items for working with the library
+//! 
+//! Games only list the ids of their platforms, genres, developers, moderators, etc.
+//! A [`Translator`] looks those ids up and remembers the names it has found,
+//! so translating a lot of games only looks up each id once.
+//! 
+//! # Example:
+//! 
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), speedrunapi::Error> {
+//! use speedrunapi::{Client, GameData};
+//! use speedrunapi::translate::Translator;
+//! let client = Client::new();
+//! let translator = Translator::new(&client);
+//! let game = GameData::new("mc").send(&client).await?;
+//! let resolved = game.resolve(&translator).await?;
+//! println!("{}", resolved);
+//! # Ok(())
+//! # }
+//! ```
+//! This will print "Minecraft: Java Edition — PC — Action, Sandbox"
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use crate::types::Game;
+use crate::{Client, Error};
+use crate::{GameTypeData, UserData, PlatformData, RegionData, GenreData, EngineData, DeveloperData, PublisherData};
+
+/// The kinds of ids a [`Translator`] can look up
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Kind{
+    GameType,
+    Platform,
+    Region,
+    Genre,
+    Engine,
+    Developer,
+    Publisher,
+    User,
+}
+
+/// Looks up the names of ids and remembers them
+/// 
+/// Cloning a translator is cheap and the clones share the names they have found,
+/// so one translator can be used by many tasks at once.
+#[derive(Debug, Clone)]
+pub struct Translator{
+    client: Client,
+    names: Arc<Mutex<HashMap<(Kind, String), String>>>,
+}
+
+/// A game with the ids of its metadata translated to names, created with [`Game::resolve`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedGame{
+    pub name: String,
+    pub gametypes: Vec<String>,
+    pub platforms: Vec<String>,
+    pub regions: Vec<String>,
+    pub genres: Vec<String>,
+    pub engines: Vec<String>,
+    pub developers: Vec<String>,
+    pub publishers: Vec<String>,
+    /// The names of the moderators of the game
+    pub moderators: Vec<String>,
+}
+
+impl Translator{
+
+    /// Creates a new Translator that sends its requests through the client
+    /// 
+    /// # Arguments:
+    /// 
+    /// `client: &Client` - The client to send the requests with
+    pub fn new(client: &Client) -> Translator{
+        Translator{
+            client: client.clone(),
+            names: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Returns the name of a gametype
+    pub async fn gametype(&self, id: &str) -> Result<String, Error>{
+        self.name(Kind::GameType, id).await
+    }
+
+    /// Returns the name of a platform
+    pub async fn platform(&self, id: &str) -> Result<String, Error>{
+        self.name(Kind::Platform, id).await
+    }
+
+    /// Returns the name of a region
+    pub async fn region(&self, id: &str) -> Result<String, Error>{
+        self.name(Kind::Region, id).await
+    }
+
+    /// Returns the name of a genre
+    pub async fn genre(&self, id: &str) -> Result<String, Error>{
+        self.name(Kind::Genre, id).await
+    }
+
+    /// Returns the name of an engine
+    pub async fn engine(&self, id: &str) -> Result<String, Error>{
+        self.name(Kind::Engine, id).await
+    }
+
+    /// Returns the name of a developer
+    pub async fn developer(&self, id: &str) -> Result<String, Error>{
+        self.name(Kind::Developer, id).await
+    }
+
+    /// Returns the name of a publisher
+    pub async fn publisher(&self, id: &str) -> Result<String, Error>{
+        self.name(Kind::Publisher, id).await
+    }
+
+    /// Returns the name of a user
+    pub async fn user(&self, id: &str) -> Result<String, Error>{
+        self.name(Kind::User, id).await
+    }
+
+    /// Forgets every name the translator has found
+    pub fn clear(&self){
+        self.names.lock().unwrap_or_else(|err| err.into_inner()).clear();
+    }
+
+    /// Returns the remembered name for the id, or looks it up and remembers it
+    async fn name(&self, kind: Kind, id: &str) -> Result<String, Error>{
+        let key = (kind, id.to_string());
+        if let Some(name) = self.names.lock().unwrap_or_else(|err| err.into_inner()).get(&key){
+            return Ok(name.clone());
+        }
+        let client = &self.client;
+        let name = match kind{
+            Kind::GameType => GameTypeData::new(id).send(client).await?.name,
+            Kind::Platform => PlatformData::new(id).send(client).await?.name,
+            Kind::Region => RegionData::new(id).send(client).await?.name,
+            Kind::Genre => GenreData::new(id).send(client).await?.name,
+            Kind::Engine => EngineData::new(id).send(client).await?.name,
+            Kind::Developer => DeveloperData::new(id).send(client).await?.name,
+            Kind::Publisher => PublisherData::new(id).send(client).await?.name,
+            Kind::User => UserData::new(id).send(client).await?.names.international,
+        };
+        self.names.lock().unwrap_or_else(|err| err.into_inner()).insert(key, name.clone());
+        Ok(name)
+    }
+
+    /// Returns the names of every id in the list
+    async fn names(&self, kind: Kind, ids: &Option<Vec<String>>) -> Result<Vec<String>, Error>{
+        let mut names = Vec::new();
+        for id in ids.iter().flatten(){
+            names.push(self.name(kind, id).await?);
+        }
+        Ok(names)
+    }
+}
+
+impl Game{
+
+    /// Translates the ids of the games metadata and moderators to names
+    /// 
+    /// ## Arguments:
+    /// 
+    /// `translator: &Translator` - The translator to look the names up with
+    /// 
+    /// ## Returns:
+    /// 
+    /// The game with names instead of ids, or the [`Error`] if one of the names could not be looked up
+    /// 
+    /// ## Example:
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, GameData};
+    /// use speedrunapi::translate::Translator;
+    /// let client = Client::new();
+    /// let translator = Translator::new(&client);
+    /// let game = GameData::new("mc").send(&client).await?.resolve(&translator).await?;
+    /// assert_eq!(game.platforms, ["PC"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn resolve(&self, translator: &Translator) -> Result<ResolvedGame, Error>{
+        let mut moderators = Vec::new();
+        for id in self.moderators.keys(){
+            moderators.push(translator.user(id).await?);
+        }
+        Ok(ResolvedGame{
+            name: self.names.international.clone(),
+            gametypes: translator.names(Kind::GameType, &self.gametypes).await?,
+            platforms: translator.names(Kind::Platform, &self.platforms).await?,
+            regions: translator.names(Kind::Region, &self.regions).await?,
+            genres: translator.names(Kind::Genre, &self.genres).await?,
+            engines: translator.names(Kind::Engine, &self.engines).await?,
+            developers: translator.names(Kind::Developer, &self.developers).await?,
+            publishers: translator.names(Kind::Publisher, &self.publishers).await?,
+            moderators,
+        })
+    }
+}
+
+impl fmt::Display for ResolvedGame{
+    /// Writes the name, platforms and genres of the game, such as "Minecraft: Java Edition — PC — Action, Sandbox"
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result{
+        write!(f, "{}", self.name)?;
+        for list in [&self.platforms, &self.genres]{
+            if !list.is_empty(){
+                write!(f, " — {}", list.join(", "))?;
+            }
+        }
+        Ok(())
+    }
+}
 
 /// This function helps with translating time
 /// 
@@ -18,7 +232,6 @@
 /// let result = tl_time!("2014-10-02T12:34:23Z");
 /// assert_eq!(result, "2014-10-02 12:34:23");
 /// ```
-
 #[macro_export]
 #[cfg(feature = "translations")]
 macro_rules! tl_time {
@@ -26,3 +239,13 @@ macro_rules! tl_time {
         chrono::DateTime::parse_from_rfc3339(&$time).unwrap().format("%Y-%m-%d %H:%M:%S").to_string()
     };
 }
+
+/// Translates a time the same way as [`tl_time!`], returning the time as it is if it is not in the format rfc3339
+///
+/// This is used by the getters, so a date speedrun.com sent in another format does not panic.
+pub(crate) fn time(time: &str) -> String{
+    match chrono::DateTime::parse_from_rfc3339(time){
+        Ok(time) => time.format("%Y-%m-%d %H:%M:%S").to_string(),
+        Err(_) => time.to_string(),
+    }
+}
@@ -0,0 +1,18 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// One leaderboard of a game, a category with a level and subcategory values if it has them
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Board{
+    /// The name shown for the leaderboard, such as "Any% Glitchless - 1.16+"
+    pub name: String,
+    /// The id or abbreviation of the game
+    pub game: String,
+    /// The id of the category
+    pub category: String,
+    /// The id of the level, full game leaderboards have no level
+    pub level: Option<String>,
+    /// The ids of the subcategory variables mapped to the ids of their values
+    pub values: BTreeMap<String, String>,
+}
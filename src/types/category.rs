@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Link, Game, Variable};
+
+/// A category of a game, such as Any% or 100%
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(remote = "Self")]
+pub struct Category{
+    pub id: String,
+    pub name: String,
+    pub weblink: String,
+    /// If the category is for the full game or for levels
+    #[serde(rename = "type")]
+    pub category_type: CategoryType,
+    pub rules: Option<String>,
+    /// How many players a run of the category has
+    pub players: Players,
+    /// Miscellaneous categories are shown apart from the main categories on speedrun.com
+    pub miscellaneous: bool,
+    #[serde(default)]
+    pub links: Vec<Link>,
+    /// The resources embedded into the category, see [`CategoryEmbed`](crate::embed::CategoryEmbed)
+    #[serde(default, skip_serializing_if = "super::is_default")]
+    pub embedded: CategoryEmbeds,
+}
+
+Embeddable!(Category,
+    "game" => Nothing,
+    "variables" => Nothing,
+);
+
+/// The resources embedded into a category, every field is None unless it was embedded
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CategoryEmbeds{
+    pub game: Option<Game>,
+    pub variables: Option<Vec<Variable>>,
+}
+
+/// If a category is for the full game or for levels
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CategoryType{
+    /// A full game category
+    PerGame,
+    /// An individual level category, used for every level of the game
+    PerLevel,
+}
+
+/// How many players a run of a category has
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(tag = "type", content = "value", rename_all = "kebab-case")]
+pub enum Players{
+    /// Every run has exactly this many players
+    Exactly(u32),
+    /// Runs can have any number of players up to this many
+    UpTo(u32),
+}
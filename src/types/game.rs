@@ -1,90 +1,163 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize)]
-pub struct GameData{
-    pub(crate) data: Game,
+use super::{Link, User, Level, Category, GameType, Variable};
+use super::metadata::{Platform, Region, Genre, Engine, Developer, Publisher};
+
+/// A game on speedrun.com
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(remote = "Self")]
+pub struct Game{
+    pub id: String,
+    pub names: GameNames,
+    /// How many boosts the game has received
+    #[serde(rename = "boostReceived")]
+    pub boosts_received: Option<i32>,
+    /// How many different users have boosted the game
+    #[serde(rename = "boostDistinctDonors")]
+    pub boost_distinct_donors: Option<i32>,
+    pub abbreviation: String,
+    pub weblink: String,
+    /// The invite to the games Discord server, None if it has none
+    #[serde(deserialize_with = "super::empty_as_none", serialize_with = "super::none_as_empty")]
+    pub discord: Option<String>,
+    /// The year the game was released
+    pub released: i16,
+    #[serde(rename = "release-date")]
+    pub release_date: Option<String>,
+    pub ruleset: GameRuleset,
+    pub romhack: bool,
+    /// The ids of the games gametypes
+    pub gametypes: Option<Vec<String>>,
+    /// The ids of the games platforms
+    pub platforms: Option<Vec<String>>,
+    /// The ids of the games regions
+    pub regions: Option<Vec<String>>,
+    /// The ids of the games genres
+    pub genres: Option<Vec<String>>,
+    /// The ids of the games engines
+    pub engines: Option<Vec<String>>,
+    /// The ids of the games developers
+    pub developers: Option<Vec<String>>,
+    /// The ids of the games publishers
+    pub publishers: Option<Vec<String>>,
+    /// The ids of the games moderators mapped to their role
+    ///
+    /// The roles are None when the moderators were embedded.
+    pub moderators: BTreeMap<String, Option<ModeratorRole>>,
+    /// When the game was added to speedrun.com, None for games added before this was recorded
+    pub created: Option<String>,
+    pub assets: GameAssets,
+    pub links: Vec<Link>,
+    /// The resources embedded into the game, see [`GameEmbed`](crate::embed::GameEmbed)
+    #[serde(default, skip_serializing_if = "super::is_default")]
+    pub embedded: GameEmbeds,
+}
+
+Embeddable!(Game,
+    "levels" => Nothing,
+    "categories" => Nothing,
+    "moderators" => Moderators,
+    "gametypes" => List,
+    "platforms" => List,
+    "regions" => List,
+    "genres" => List,
+    "engines" => List,
+    "developers" => List,
+    "publishers" => List,
+    "variables" => Nothing,
+);
+
+/// The resources embedded into a game, every field is None unless it was embedded
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GameEmbeds{
+    pub levels: Option<Vec<Level>>,
+    pub categories: Option<Vec<Category>>,
+    pub moderators: Option<Vec<User>>,
+    pub gametypes: Option<Vec<GameType>>,
+    pub platforms: Option<Vec<Platform>>,
+    pub regions: Option<Vec<Region>>,
+    pub genres: Option<Vec<Genre>>,
+    pub engines: Option<Vec<Engine>>,
+    pub developers: Option<Vec<Developer>>,
+    pub publishers: Option<Vec<Publisher>>,
+    pub variables: Option<Vec<Variable>>,
+}
+
+/// The role of a moderator of a game or series
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ModeratorRole{
+    Moderator,
+    SuperModerator,
+    /// Can only verify runs
+    Verifier,
+    /// A role speedrun.com added after this version of the crate
+    #[serde(other)]
+    Unknown,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-pub struct Game {
-    pub(crate) id: String,
-    pub(crate) names: GameNames,
-    pub(crate) boosts_received: Option<i32>,
-    pub(crate) boost_distinct_donors: Option<i32>,
-    pub(crate) abbreviation: String,
-    pub(crate) weblink: String,
-    pub(crate) discord: String,
-    pub(crate) released: i16,
-    pub(crate) release_date: Option<String>,
-    pub(crate) ruleset: GameRuleset,
-    pub(crate) romhack: bool,
-    pub(crate) gametypes: Option<Vec<String>>,
-    pub(crate) platforms: Option<Vec<String>>,
-    pub(crate) regions: Option<Vec<String>>,
-    pub(crate) genres: Option<Vec<String>>,
-    pub(crate) engines: Option<Vec<String>>,
-    pub(crate) developers: Option<Vec<String>>,
-    pub(crate) publishers: Option<Vec<String>>,
-    pub(crate) moderators: serde_json::Map<String, serde_json::Value>,
-    pub(crate) created: String,
-    pub(crate) assets: GameAssets,
-    pub(crate) links: Vec<GameLink>,
+/// The few fields of a game speedrun.com returns in bulk mode
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct BulkGame{
+    pub id: String,
+    pub names: GameNames,
+    pub abbreviation: String,
+    pub weblink: String,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-pub struct GameNames {
-    pub(crate) international: String,
-    pub(crate) japanese: Option<String>,
-    pub(crate) twitch: String,
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GameNames{
+    pub international: String,
+    pub japanese: Option<String>,
+    pub twitch: String,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-pub struct GameRuleset {
+/// The rules every run of the game is checked against
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GameRuleset{
     #[serde(rename = "show-milliseconds")]
-    pub(crate) show_milliseconds: bool,
+    pub show_milliseconds: bool,
     #[serde(rename = "require-verification")]
-    pub(crate) require_verification: bool,
+    pub require_verification: bool,
     #[serde(rename = "require-video")]
-    pub(crate) require_video: bool,
+    pub require_video: bool,
+    /// The timing methods used by the game, such as "realtime" or "ingame"
     #[serde(rename = "run-times")]
-    pub(crate) run_times: Vec<String>,
+    pub run_times: Vec<String>,
     #[serde(rename = "default-time")]
-    pub(crate) default_time: String,
+    pub default_time: String,
     #[serde(rename = "emulators-allowed")]
-    pub(crate) emulators_allowed: bool,
+    pub emulators_allowed: bool,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-pub struct GameAssets {
-    pub(crate) logo: GameAssetUri,
+/// The images speedrun.com has for a game
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GameAssets{
+    pub logo: GameAssetUri,
     #[serde(rename = "cover-tiny")]
-    pub(crate) cover_tiny: GameAssetUri,
+    pub cover_tiny: GameAssetUri,
     #[serde(rename = "cover-small")]
-    pub(crate) cover_small: GameAssetUri,
+    pub cover_small: GameAssetUri,
     #[serde(rename = "cover-medium")]
-    pub(crate) cover_medium: GameAssetUri,
+    pub cover_medium: GameAssetUri,
     #[serde(rename = "cover-large")]
-    pub(crate) cover_large: GameAssetUri,
-    pub(crate) icon: GameAssetUri,
+    pub cover_large: GameAssetUri,
+    pub icon: GameAssetUri,
     #[serde(rename = "trophy-1st")]
-    pub(crate) trophy_1st: GameAssetUri,
+    pub trophy_1st: GameAssetUri,
     #[serde(rename = "trophy-2nd")]
-    pub(crate) trophy_2nd: GameAssetUri,
+    pub trophy_2nd: GameAssetUri,
     #[serde(rename = "trophy-3rd")]
-    pub(crate) trophy_3rd: GameAssetUri,
+    pub trophy_3rd: GameAssetUri,
     #[serde(rename = "trophy-4th")]
-    pub(crate) trophy_4th: Option<GameAssetUri>,
-    pub(crate) background: GameAssetUri,
-    pub(crate) foreground: Option<GameAssetUri>,
+    pub trophy_4th: Option<GameAssetUri>,
+    pub background: GameAssetUri,
+    pub foreground: Option<GameAssetUri>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-pub struct GameAssetUri {
-    pub(crate) uri: Option<String>,
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GameAssetUri{
+    pub uri: Option<String>,
 }
-
-#[derive(Debug, Deserialize, Serialize)]
-pub struct GameLink {
-    pub(crate) rel: String,
-    pub(crate) uri: String,
-}
\ No newline at end of file
@@ -1,20 +1,15 @@
-use serde::{Deserialize};
-#[allow(dead_code, unused_variables)]
+use serde::{Deserialize, Serialize};
 
-#[derive(Deserialize, Debug)]
-pub struct Link {
-    pub(crate) rel: String,
-    pub(crate) uri: String,
-}
+use super::Link;
 
-#[derive(Deserialize, Debug)]
+/// A game type, such as ROM Hack or Fangame
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub struct GameType{
-    pub(crate) id: String,
-    pub(crate) name: String,
-   // pub(crate) links: Vec<Link>,
+    pub id: String,
+    pub name: String,
+    /// If games of this type need a base game to be set
+    #[serde(rename = "allows-base-game", default)]
+    pub allows_base_game: bool,
+    #[serde(default)]
+    pub links: Vec<Link>,
 }
-
-#[derive(Deserialize, Debug)]
-pub struct GameTypeData{
-    pub(crate) data: GameType,
-}
\ No newline at end of file
@@ -1,18 +1,10 @@
-use serde::{Serialize, Deserialize};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize)]
-pub struct Links{
-    pub(crate) rel: String,
-    pub(crate) uri: String,
-}
+use super::Link;
 
-#[derive(Debug, Deserialize, Serialize)]
+/// A runner without a speedrun.com account
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub struct Guest{
-    pub(crate) name: String,
-    pub(crate) links: Links,
+    pub name: String,
+    pub links: Vec<Link>,
 }
-
-#[derive(Debug, Deserialize, Serialize)]
-pub struct GuestData{
-    pub(crate) data: Guest,
-}
\ No newline at end of file
@@ -0,0 +1,68 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Link, Run, Game, Category, Level, Variable, EmbeddedPlayer};
+use super::metadata::{Platform, Region};
+
+/// The ranked runs of a category, or of a category of a level
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(remote = "Self")]
+pub struct Leaderboard{
+    pub weblink: Option<String>,
+    /// The id of the game
+    pub game: String,
+    /// The id of the category
+    pub category: String,
+    /// The id of the level, full game leaderboards have no level
+    pub level: Option<String>,
+    /// The id of the platform the leaderboard was filtered by
+    pub platform: Option<String>,
+    /// The id of the region the leaderboard was filtered by
+    pub region: Option<String>,
+    /// If emulated runs were filtered in or out
+    pub emulators: Option<bool>,
+    #[serde(rename = "video-only")]
+    pub video_only: bool,
+    /// The timing method the runs are ranked by
+    pub timing: Option<String>,
+    /// The ids of the variables mapped to the ids of the values the leaderboard was filtered by
+    pub values: BTreeMap<String, String>,
+    pub runs: Vec<PlacedRun>,
+    #[serde(default)]
+    pub links: Vec<Link>,
+    /// The resources embedded into the leaderboard, see [`LeaderboardEmbed`](crate::embed::LeaderboardEmbed)
+    #[serde(default, skip_serializing_if = "super::is_default")]
+    pub embedded: LeaderboardEmbeds,
+}
+
+Embeddable!(Leaderboard,
+    "game" => One,
+    "category" => One,
+    "level" => One,
+    "players" => Nothing,
+    "regions" => Nothing,
+    "platforms" => Nothing,
+    "variables" => Nothing,
+);
+
+/// The resources embedded into a leaderboard, every field is None unless it was embedded
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct LeaderboardEmbeds{
+    pub game: Option<Game>,
+    pub category: Option<Category>,
+    pub level: Option<Level>,
+    /// The players of every run on the leaderboard
+    pub players: Option<Vec<EmbeddedPlayer>>,
+    pub regions: Option<Vec<Region>>,
+    pub platforms: Option<Vec<Platform>>,
+    pub variables: Option<Vec<Variable>>,
+}
+
+/// A run and where it places on a leaderboard
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PlacedRun{
+    /// The place of the run, runs with the same time share a place
+    pub place: u32,
+    pub run: Run,
+}
@@ -1,21 +1,30 @@
-use serde::{Serialize, Deserialize};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize)]
-pub struct LevelData{
-    pub(crate) data: Level,
-}
+use super::{Link, Category, Variable};
 
-#[derive(Debug, Deserialize, Serialize)]
+/// An individual level of a game
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(remote = "Self")]
 pub struct Level{
-    pub(crate) id: String,
-    pub(crate) name: String,
-    pub(crate) weblink: String,
-    pub(crate) rules: String,
-    pub(crate) links: Vec<Links>,
+    pub id: String,
+    pub name: String,
+    pub weblink: String,
+    /// The rules of the level, None if it has none
+    pub rules: Option<String>,
+    pub links: Vec<Link>,
+    /// The resources embedded into the level, see [`LevelEmbed`](crate::embed::LevelEmbed)
+    #[serde(default, skip_serializing_if = "super::is_default")]
+    pub embedded: LevelEmbeds,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-pub struct Links{
-    pub(crate) rel: String,
-    pub(crate) uri: String,
-}
\ No newline at end of file
+Embeddable!(Level,
+    "categories" => Nothing,
+    "variables" => Nothing,
+);
+
+/// The resources embedded into a level, every field is None unless it was embedded
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct LevelEmbeds{
+    pub categories: Option<Vec<Category>>,
+    pub variables: Option<Vec<Variable>>,
+}
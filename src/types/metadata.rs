@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+use super::Link;
+
+/// A platform games are played on, such as PC or Nintendo Switch
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Platform{
+    pub id: String,
+    pub name: String,
+    /// The year the platform was released
+    pub released: i32,
+    #[serde(default)]
+    pub links: Vec<Link>,
+}
+
+/// Generates a model for the metadata that only has an id, a name and links
+macro_rules! Metadata_Model {
+    ($doc:expr, $name:ident) => {
+        #[doc = $doc]
+        #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+        pub struct $name{
+            pub id: String,
+            pub name: String,
+            #[serde(default)]
+            pub links: Vec<Link>,
+        }
+    };
+}
+
+Metadata_Model!("A region games are released in, such as USA / NTSC", Region);
+Metadata_Model!("A genre of games, such as Action or Puzzle", Genre);
+Metadata_Model!("An engine games are made with, such as Unity", Engine);
+Metadata_Model!("A developer of games", Developer);
+Metadata_Model!("A publisher of games", Publisher);
@@ -1,21 +1,282 @@
 //! # Types
 //! 
-//! This section contains the JSON values for easy formating and extraction of the data
+//! This section contains the models the data from speedrun.com is decoded into.
 //! 
-//! **NOTE:** This section is intedned for use inside the crate only,
-//! if you want to venture and you this area in you own project go ahead, but you may encounter issues.
+//! Every model has public fields and can be cloned, compared and serialized again,
+//! so what you fetch can be stored in your own database or returned from your own API.
+//! The field names match the rust style, but they serialize back to the same names speedrun.com uses.
+//! 
+//! The request builders return these models directly, for example [`UserData::send`](crate::UserData::send) returns a [`User`].
+//! 
+//! # Example:
+//! 
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use speedrunapi::{Client, GameData};
+//! let client = Client::new();
+//! let game = GameData::new("mc").send(&client).await?;
+//! println!("{}", game.names.international);
+//! let json = serde_json::to_string(&game)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::de::{self, DeserializeSeed, IntoDeserializer, MapAccess};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Generates Deserialize and Serialize for a model other resources can be embedded into.
+///
+/// speedrun.com puts an embedded resource where its id was, wrapped in `{"data": ...}`,
+/// so the resources are moved to the `embedded` field of the model and their ids are put back while the fields are decoded.
+/// The model derives the functions for its fields with `#[serde(remote = "Self")]`, which are called from here.
+macro_rules! Embeddable {
+    ($model:ident, $($key:literal => $ids:ident),+ $(,)?) => {
+        impl<'de> serde::Deserialize<'de> for $model{
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<$model, D::Error>{
+                struct Visitor;
+
+                impl<'de> serde::de::Visitor<'de> for Visitor{
+                    type Value = $model;
+
+                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result{
+                        f.write_str(concat!("a ", stringify!($model)))
+                    }
+
+                    fn visit_map<A: serde::de::MapAccess<'de>>(self, map: A) -> Result<$model, A::Error>{
+                        let map = super::Unembed::new(map, &[$(($key, super::Ids::$ids)),+]);
+                        $model::deserialize(serde::de::value::MapAccessDeserializer::new(map))
+                    }
+                }
+
+                deserializer.deserialize_map(Visitor)
+            }
+        }
+
+        impl serde::Serialize for $model{
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>{
+                $model::serialize(self, serializer)
+            }
+        }
+    };
+}
 
 pub mod user;
-pub use user::UserData;
+pub use user::User;
 
 pub mod guest;
-pub use guest::GuestData;
+pub use guest::Guest;
 
 pub mod game;
-pub use game::GameData;
+pub use game::{Game, BulkGame, GameEmbeds, ModeratorRole};
 
 pub mod gametype;
-pub use gametype::GameTypeData;
+pub use gametype::GameType;
 
 pub mod levels;
-pub use levels::LevelData;
+pub use levels::{Level, LevelEmbeds};
+
+pub mod run;
+pub use run::{Run, RunStatus, Player, EmbeddedPlayer, RunEmbeds};
+
+pub mod leaderboard;
+pub use leaderboard::{Leaderboard, PlacedRun, LeaderboardEmbeds};
+
+pub mod category;
+pub use category::{Category, CategoryType, Players, CategoryEmbeds};
+
+pub mod variable;
+pub use variable::{Variable, VariableScope, VariableValue};
+
+pub mod personal_best;
+pub use personal_best::PersonalBest;
+
+pub mod board;
+pub use board::Board;
+
+pub mod metadata;
+pub use metadata::{Platform, Region, Genre, Engine, Developer, Publisher};
+
+pub mod series;
+pub use series::{Series, SeriesEmbeds};
+
+/// The wrapper speedrun.com puts around every single resource it returns
+#[derive(Debug, Deserialize)]
+pub(crate) struct Data<T>{
+    pub(crate) data: T,
+}
+
+/// The wrapper speedrun.com puts around a list, with where the page is in the list
+#[derive(Debug, Deserialize)]
+pub(crate) struct Page<T>{
+    pub(crate) data: Vec<T>,
+    pub(crate) pagination: Option<Pagination>,
+}
+
+impl<T> Page<T>{
+
+    /// Returns the link to the next page, or None on the last page
+    pub(crate) fn next(&self) -> Option<&str>{
+        let pagination = self.pagination.as_ref()?;
+        pagination.links.iter().find(|link| link.rel == "next").map(|link| link.uri.as_str())
+    }
+}
+
+/// Where a page is in a list
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Pagination{
+    /// How many items come before the page
+    pub offset: u32,
+    /// How many items the page can have
+    pub max: u32,
+    /// How many items the page has
+    pub size: u32,
+    /// The links to the previous and next pages
+    pub links: Vec<Link>,
+}
+
+/// A link from one resource to another
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Link{
+    /// What the link points to, such as "self", "runs" or "games"
+    pub rel: String,
+    /// The url of the linked resource
+    pub uri: String,
+}
+
+/// How the ids of an embedded resource are put back where the resource was
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Ids{
+    /// The key is only there when the resources are embedded
+    Nothing,
+    /// The key is only there when the resource is embedded, which is an empty list if there is nothing to embed
+    Single,
+    /// A list of ids
+    List,
+    /// One id, or null if there is nothing to embed, such as the level of a full game run
+    One,
+    /// The players of a run, as users and guests
+    Players,
+    /// The moderators mapped to their roles, which the embedded users don't have so they are null
+    Moderators,
+}
+
+/// Reads the fields of a model, moving the resources embedded under the keys to the `embedded` field
+/// and putting their ids back in their place
+///
+/// Every other field is passed on as it is, so an error decoding it keeps its path.
+pub(crate) struct Unembed<A>{
+    map: A,
+    keys: &'static [(&'static str, Ids)],
+    /// The key and value that were read to check if the key was embedded, which are decoded from here
+    value: Option<(String, Value)>,
+    embedded: Map<String, Value>,
+    done: bool,
+}
+
+impl<A> Unembed<A>{
+    pub(crate) fn new(map: A, keys: &'static [(&'static str, Ids)]) -> Unembed<A>{
+        Unembed{
+            map,
+            keys,
+            value: None,
+            embedded: Map::new(),
+            done: false,
+        }
+    }
+}
+
+impl<'de, A: MapAccess<'de>> MapAccess<'de> for Unembed<A>{
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, A::Error>{
+        while !self.done{
+            let Some(key) = self.map.next_key::<String>()? else{
+                self.done = true;
+                break;
+            };
+            let Some(&(_, ids)) = self.keys.iter().find(|(embed, _)| *embed == key) else{
+                return seed.deserialize(key.into_deserializer()).map(Some);
+            };
+            let value = match self.map.next_value::<Value>()?{
+                Value::Object(mut wrapper) if wrapper.len() == 1 && wrapper.contains_key("data") => {
+                    let (replaced, data) = unembed(wrapper.remove("data").unwrap_or_default(), ids);
+                    self.embedded.insert(key.clone(), data);
+                    match replaced{
+                        Some(replaced) => replaced,
+                        None => continue,
+                    }
+                }
+                value => value,
+            };
+            self.value = Some((key.clone(), value));
+            return seed.deserialize(key.into_deserializer()).map(Some);
+        }
+        if self.embedded.is_empty(){
+            return Ok(None);
+        }
+        let embedded = Value::Object(std::mem::take(&mut self.embedded));
+        self.value = Some(("embedded".to_string(), embedded));
+        seed.deserialize("embedded".into_deserializer()).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, A::Error>{
+        let Some((key, value)) = self.value.take() else{
+            return self.map.next_value_seed(seed);
+        };
+        let mut track = serde_path_to_error::Track::new();
+        seed.deserialize(serde_path_to_error::Deserializer::new(value, &mut track)).map_err(|err| {
+            let path = track.path().to_string();
+            match path.as_str(){
+                "." => de::Error::custom(format_args!("{} at `{}`", err, key)),
+                _ => de::Error::custom(format_args!("{} at `{}.{}`", err, key, path)),
+            }
+        })
+    }
+}
+
+/// Splits the data of an embedded resource into the ids that are put back where it was, None if its key is removed,
+/// and the resource that is moved to the `embedded` field
+fn unembed(data: Value, ids: Ids) -> (Option<Value>, Value){
+    let items = data.as_array().map(|items| items.as_slice()).unwrap_or_default();
+    let replaced = match ids{
+        Ids::Nothing | Ids::Single => None,
+        Ids::List => Some(items.iter().map(|item| item["id"].clone()).collect()),
+        Ids::One => Some(data["id"].clone()),
+        Ids::Players => Some(items.iter().map(player).collect()),
+        Ids::Moderators => Some(Value::Object(items.iter().filter_map(|item| item["id"].as_str()).map(|id| (id.to_string(), Value::Null)).collect())),
+    };
+    match ids{
+        Ids::One | Ids::Single if !data.is_object() => (replaced, Value::Null),
+        _ => (replaced, data),
+    }
+}
+
+/// Returns the reference to an embedded user or guest a run has in its players
+fn player(item: &Value) -> Value{
+    let uri = item["links"].as_array()
+        .and_then(|links| links.iter().find(|link| link["rel"] == "self"))
+        .map(|link| link["uri"].clone())
+        .unwrap_or_else(|| Value::from(""));
+    match item["rel"].as_str(){
+        Some("guest") => serde_json::json!({ "rel": "guest", "name": item["name"], "uri": uri }),
+        _ => serde_json::json!({ "rel": "user", "id": item["id"], "uri": uri }),
+    }
+}
+
+/// Decodes text speedrun.com leaves empty when there is nothing, such as the Discord of a game, as None
+pub(crate) fn empty_as_none<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error>{
+    let text = Option::<String>::deserialize(deserializer)?;
+    Ok(text.filter(|text| !text.is_empty()))
+}
+
+/// Encodes None as the empty text speedrun.com sends for it
+pub(crate) fn none_as_empty<S: serde::Serializer>(text: &Option<String>, serializer: S) -> Result<S::Ok, S::Error>{
+    serializer.serialize_str(text.as_deref().unwrap_or_default())
+}
+
+/// Returns true if the value is the default, used to leave out the `embedded` field when nothing was embedded
+pub(crate) fn is_default<T: Default + PartialEq>(value: &T) -> bool{
+    *value == T::default()
+}
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Run, RunEmbeds};
+
+/// One of a users personal bests and where it places on its leaderboard
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(remote = "Self")]
+pub struct PersonalBest{
+    /// The place of the run on its leaderboard
+    pub place: u32,
+    pub run: Run,
+    /// The resources embedded next to the run, see [`PersonalBestEmbed`](crate::embed::PersonalBestEmbed)
+    #[serde(default, skip_serializing_if = "super::is_default")]
+    pub embedded: RunEmbeds,
+}
+
+Embeddable!(PersonalBest,
+    "game" => Single,
+    "category" => Single,
+    "level" => Single,
+    "players" => Nothing,
+    "region" => Single,
+    "platform" => Single,
+);
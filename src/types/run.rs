@@ -0,0 +1,138 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Link, User, Guest, Game, Category, Level};
+use super::metadata::{Platform, Region};
+
+/// A run submitted to speedrun.com
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(remote = "Self")]
+pub struct Run{
+    pub id: String,
+    pub weblink: Option<String>,
+    /// The id of the game the run is for
+    pub game: String,
+    /// The id of the level the run is for, full game runs have no level
+    pub level: Option<String>,
+    /// The id of the category the run is for
+    pub category: String,
+    pub videos: Option<Videos>,
+    pub comment: Option<String>,
+    pub status: RunStatus,
+    pub players: Vec<Player>,
+    /// The day the run was done on
+    pub date: Option<String>,
+    /// When the run was submitted to speedrun.com
+    pub submitted: Option<String>,
+    pub times: Times,
+    pub system: System,
+    pub splits: Option<Link>,
+    /// The ids of the variables mapped to the ids of the values chosen for the run
+    pub values: BTreeMap<String, String>,
+    #[serde(default)]
+    pub links: Vec<Link>,
+    /// The resources embedded into the run, see [`RunEmbed`](crate::embed::RunEmbed)
+    #[serde(default, skip_serializing_if = "super::is_default")]
+    pub embedded: RunEmbeds,
+}
+
+Embeddable!(Run,
+    "game" => One,
+    "category" => One,
+    "level" => One,
+    "players" => Players,
+    "region" => Single,
+    "platform" => Single,
+);
+
+/// The resources embedded into a run or next to the run of a personal best, every field is None unless it was embedded
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RunEmbeds{
+    pub game: Option<Game>,
+    pub category: Option<Category>,
+    pub level: Option<Level>,
+    pub players: Option<Vec<EmbeddedPlayer>>,
+    pub region: Option<Region>,
+    pub platform: Option<Platform>,
+}
+
+/// The videos of a run
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Videos{
+    /// Text the runner wrote instead of a video link
+    pub text: Option<String>,
+    pub links: Option<Vec<VideoLink>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct VideoLink{
+    pub uri: String,
+}
+
+/// Where a run is in the verification process
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(tag = "status", rename_all = "lowercase")]
+pub enum RunStatus{
+    /// The run has not been looked at yet
+    New,
+    Verified{
+        /// The id of the moderator who verified the run
+        examiner: Option<String>,
+        #[serde(rename = "verify-date")]
+        verify_date: Option<String>,
+    },
+    Rejected{
+        /// The id of the moderator who rejected the run
+        examiner: Option<String>,
+        reason: Option<String>,
+    },
+}
+
+/// A runner in a run, either a user or a guest
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(tag = "rel", rename_all = "lowercase")]
+pub enum Player{
+    User{
+        id: String,
+        uri: String,
+    },
+    Guest{
+        name: String,
+        uri: String,
+    },
+}
+
+/// A runner that was embedded into a run or a leaderboard
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(tag = "rel", rename_all = "lowercase")]
+pub enum EmbeddedPlayer{
+    User(Box<User>),
+    Guest(Guest),
+}
+
+/// The times of a run
+///
+/// The text fields are ISO 8601 durations such as "PT1H17M44S",
+/// the `_t` fields are the same times in seconds.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Times{
+    pub primary: String,
+    pub primary_t: f64,
+    pub realtime: Option<String>,
+    pub realtime_t: f64,
+    pub realtime_noloads: Option<String>,
+    pub realtime_noloads_t: f64,
+    pub ingame: Option<String>,
+    pub ingame_t: f64,
+}
+
+/// What the run was played on
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct System{
+    /// The id of the platform
+    pub platform: Option<String>,
+    pub emulated: bool,
+    /// The id of the region
+    pub region: Option<String>,
+}
@@ -0,0 +1,44 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Link, User};
+use super::game::{GameAssets, ModeratorRole};
+
+/// A series of games, such as Mario or Zelda
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(remote = "Self")]
+pub struct Series{
+    pub id: String,
+    pub names: SeriesNames,
+    pub abbreviation: String,
+    pub weblink: String,
+    pub discord: Option<String>,
+    /// The ids of the series moderators mapped to their role
+    ///
+    /// The roles are None when the moderators were embedded.
+    pub moderators: BTreeMap<String, Option<ModeratorRole>>,
+    pub created: Option<String>,
+    pub assets: GameAssets,
+    #[serde(default)]
+    pub links: Vec<Link>,
+    /// The resources embedded into the series, see [`SeriesEmbed`](crate::embed::SeriesEmbed)
+    #[serde(default, skip_serializing_if = "super::is_default")]
+    pub embedded: SeriesEmbeds,
+}
+
+Embeddable!(Series,
+    "moderators" => Moderators,
+);
+
+/// The resources embedded into a series, every field is None unless it was embedded
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SeriesEmbeds{
+    pub moderators: Option<Vec<User>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SeriesNames{
+    pub international: String,
+    pub japanese: Option<String>,
+}
@@ -1,31 +1,37 @@
-use serde::{Deserialize};
+use serde::{Deserialize, Serialize};
 
-#[derive(Deserialize, Debug)]
-pub struct User {
-    pub(crate) id: String,
-    pub(crate) names: Names,
+use super::Link;
+
+/// A user with a speedrun.com account
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct User{
+    pub id: String,
+    pub names: Names,
+    /// If the user has the animated name from supporting speedrun.com
     #[serde(rename = "supporterAnimation")]
-    pub(crate) supporter_animation: bool,
-    pub(crate) pronouns: Option<String>,
-    pub(crate) weblink: String,
+    pub supporter_animation: bool,
+    pub pronouns: Option<String>,
+    pub weblink: String,
     //#[serde(rename = "name-style")]
-    //pub(crate) name_style: NameStyle,
-    pub(crate) role: String,
-    pub(crate) signup: Option<String>,
-    pub(crate) location: Location,
-    pub(crate) twitch: Option<String>,
-    pub(crate) hitbox: Option<String>,
-    pub(crate) youtube: Option<String>,
-    pub(crate) twitter: Option<String>,
-    pub(crate) speedrunslive: Option<String>,
-    pub(crate) assets: Assets,
-    pub(crate) links: Vec<Link>,
+    //pub name_style: NameStyle,
+    /// The users role on speedrun.com, such as "user" or "admin"
+    pub role: String,
+    pub signup: Option<String>,
+    /// Where the user is from, None if they have not set it
+    pub location: Option<Location>,
+    pub twitch: Option<Uri>,
+    pub hitbox: Option<Uri>,
+    pub youtube: Option<Uri>,
+    pub twitter: Option<Uri>,
+    pub speedrunslive: Option<Uri>,
+    pub assets: Assets,
+    pub links: Vec<Link>,
 }
 
-#[derive(Deserialize, Debug)]
-pub struct Names {
-    pub(crate) international: String,
-    pub(crate) japanese: Option<String>,
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Names{
+    pub international: String,
+    pub japanese: Option<String>,
 }
 /* 
 #[derive(Deserialize, Debug)]
@@ -40,53 +46,36 @@ pub struct Color {
     pub(crate) dark: String,
 }
 */
-#[derive(Deserialize, Debug)]
-pub struct Location {
-    pub(crate) country: Country,
-    pub(crate) region: Option<Region>,
+/// Where the user says they are from
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Location{
+    pub country: Country,
+    pub region: Option<Region>,
 }
 
-#[derive(Deserialize, Debug)]
-pub struct Country {
-    pub(crate) code: String,
-    pub(crate) names: Names,
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Country{
+    pub code: String,
+    pub names: Names,
 }
 
-#[derive(Deserialize, Debug)]
-pub struct Region {
-    pub(crate) code: String,
-    pub(crate) names: Names,
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Region{
+    pub code: String,
+    pub names: Names,
 }
 
-#[derive(Deserialize, Debug)]
-pub struct Assets {
-    pub(crate) icon: Icon,
+/// The images a user has uploaded
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Assets{
+    pub icon: Uri,
     #[serde(rename = "supporterIcon")]
-    pub(crate) supporter_icon: Option<Icon>,
-    pub(crate) image: Icon,
+    pub supporter_icon: Option<Uri>,
+    pub image: Uri,
 }
 
-#[derive(Deserialize, Debug)]
-pub struct Icon {
-    pub(crate) uri: Option<String>,
+/// A url that speedrun.com may leave empty
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Uri{
+    pub uri: Option<String>,
 }
-
-#[derive(Deserialize, Debug)]
-pub struct Link {
-    pub(crate) rel: String,
-    pub(crate) uri: String,
-}
-
-#[derive(Deserialize, Debug)]
-pub struct UserData {
-    pub(crate) data: User
-   // pub(crate) pagination: Pagination,
-}
-
-//#[derive(Deserialize, Debug)]
-//pub struct Pagination {
-  //  pub(crate) offset: u32,
-  //  pub(crate) max: u32,
-  //  pub(crate) size: u32,
-  //  pub(crate) links: Vec<Link>,
-//}
\ No newline at end of file
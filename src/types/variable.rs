@@ -0,0 +1,69 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use super::Link;
+
+/// A variable of a game, such as the version or difficulty a run was done on
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Variable{
+    pub id: String,
+    pub name: String,
+    /// The id of the category the variable is for, variables for every category have none
+    pub category: Option<String>,
+    /// If the variable is for the full game, for levels or for both
+    pub scope: VariableScope,
+    /// If every run has to pick a value for the variable
+    pub mandatory: bool,
+    /// If runners can type in their own value instead of picking one
+    #[serde(rename = "user-defined")]
+    pub user_defined: bool,
+    /// If the value is taken into account when deciding if one run replaces another
+    pub obsoletes: bool,
+    pub values: VariableValues,
+    /// If every value of the variable is its own leaderboard, shown as subcategories on speedrun.com
+    #[serde(rename = "is-subcategory")]
+    pub is_subcategory: bool,
+    #[serde(default)]
+    pub links: Vec<Link>,
+}
+
+/// What a variable can be used for
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub enum VariableScope{
+    /// Full game runs and runs of every level
+    Global,
+    /// Only full game runs
+    FullGame,
+    /// Only runs of levels
+    AllLevels,
+    /// Only runs of one level
+    SingleLevel{
+        /// The id of the level
+        level: String,
+    },
+}
+
+/// The values a variable can have
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct VariableValues{
+    /// The ids of the values mapped to the values, in the order speedrun.com lists them
+    pub values: IndexMap<String, VariableValue>,
+    /// The id of the value picked when a run does not pick one
+    pub default: Option<String>,
+}
+
+/// One value of a variable, such as "1.16+" for a version
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct VariableValue{
+    pub label: String,
+    /// The rules of the value, only subcategories have them
+    pub rules: Option<String>,
+    pub flags: Option<ValueFlags>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ValueFlags{
+    /// If the value is shown apart from the main values on speedrun.com
+    pub miscellaneous: Option<bool>,
+}
@@ -10,31 +10,78 @@
 //! 
 //! You can use the functions in this module to get differnt parts and peices of the data or the whole set!
 //!
+//! The personal bests of a user are fetched with their own request, see [`UserData::personal_bests`].
+//!
+//! Users can also be searched for by name or by the accounts they have linked, see [`UserData::list`]:
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), speedrunapi::Error> {
+//! use speedrunapi::{Client, UserData};
+//! let client = Client::new();
+//! let result = UserData::list().twitch("fishin_rod").send(&client).await?;
+//! println!("{:?}", result.first().map(|user| user.name()));
+//! # Ok(())
+//! # }
+//! ```
+//! This will find the speedrun.com account linked to a twitch account.
+//!
 
-use crate::types::UserData as Data;
-use crate::tl_time;
+use crate::types::Data;
+use crate::embed::PersonalBestEmbed;
+use crate::types::{User, PersonalBest};
+use crate::translate;
+use crate::{Client, Direction, Error};
 
 #[derive(Debug)]
 pub struct UserData{
     pub name: String,
-    personal_bests: bool,
 }
 
-#[derive(Debug)]
-pub enum UserResult{
-    User(Data),
-    None,
-    Error(UserError),
+List_Request!("A request for a list of users, created with [`UserData::list`]", UserSearch, "users", User);
+Sub_Resource!("A request for the personal bests of a user, created with [`UserData::personal_bests`]", PersonalBests, "users", "personal-bests", PersonalBest);
+
+/// What a list of users can be sorted by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserOrder{
+    /// The english name
+    Name,
+    /// The japanese name
+    JapaneseName,
+    /// When the user signed up
+    Signup,
+    Role,
 }
 
-#[derive(Debug)]
-pub enum UserError {
-    UserNotFound,
-    InvalidArguments,
-    ReqwestError(reqwest::Error),
+/// The format of a name returned by [`User::country`] and [`User::region`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NameType{
+    /// The name in english
+    International,
+    /// The name in japanese, can be None
+    Japanese,
+    /// The code of the country or region
+    Code,
+}
+
+/// The services a user can link on their profile, used by [`User::links`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Service{
+    Twitch,
+    Hitbox,
+    Youtube,
+    Twitter,
+    Speedrunslive,
 }
 
-impl UserResult{
+/// The assets a user can have on their profile, used by [`User::assets`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserAsset{
+    Icon,
+    SupporterIcon,
+    Image,
+}
+
+impl User{
 
     /// Returns the name of the user
     /// 
@@ -43,19 +90,18 @@ impl UserResult{
     /// The name of the user as an String
     /// 
     /// # Example:
-    /// ```rust
-    /// use speedrunapi::UserData;
-    /// let result = UserData::new("fishin_rod").run();
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, UserData};
+    /// let client = Client::new();
+    /// let result = UserData::new("fishin_rod").send(&client).await?;
     /// assert_eq!(result.name(), "fishin_rod");
+    /// # Ok(())
+    /// # }
     /// ```
-    
     pub fn name(&self) -> String {
-        if let UserResult::User(user_data) = self {
-            user_data.data.names.international.to_string()
-        }
-        else{
-            panic!("Cannot Get name from: {:?}", self);
-        }
+        self.names.international.to_string()
     }
 
     // Get japanese and inernational to be in the same function 
@@ -70,23 +116,22 @@ impl UserResult{
     /// 
     /// ## Returns:
     /// 
-    /// The Japanese name of the user as an String
-    /// The Japanese name of the user can be equal to "None"
+    /// The Japanese name of the user as an Option<String>
+    /// The Japanese name of the user is None if they have not set one
     /// 
     /// ## Example:
-    /// ```rust
-    /// use speedrunapi::UserData;
-    /// let result = UserData::new("fishin_rod").run();
-    /// assert_eq!(result.japanese_name(), "None");
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, UserData};
+    /// let client = Client::new();
+    /// let result = UserData::new("fishin_rod").send(&client).await?;
+    /// assert_eq!(result.japanese_name(), None);
+    /// # Ok(())
+    /// # }
     /// ```
-    
-    pub fn japanese_name(&self) -> String {
-        if let UserResult::User(user_data) = self {
-            user_data.data.names.japanese.clone().unwrap_or(String::from("None"))
-        }
-        else{
-            panic!("Cannot Get name from: {:?}", self);
-        }
+    pub fn japanese_name(&self) -> Option<String> {
+        self.names.japanese.clone()
     }
 
     /// Returns the id of a user
@@ -96,19 +141,18 @@ impl UserResult{
     /// The id of the user as a String
     /// 
     /// ## Example:
-    /// ```rust
-    /// use speedrunapi::UserData;
-    /// let result = UserData::new("fishin_rod").run();
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, UserData};
+    /// let client = Client::new();
+    /// let result = UserData::new("fishin_rod").send(&client).await?;
     /// assert_eq!(result.id(), "jonryvl8");
+    /// # Ok(())
+    /// # }
     /// ```
-    
     pub fn id(&self) -> String {
-        if let UserResult::User(user_data) = self {
-            user_data.data.id.clone()
-        }
-        else{
-            panic!("Cannot Get id from: {:?}", self);
-        }
+        self.id.clone()
     }
 
     /// Rerturns if the user has a supporter animation or not
@@ -118,42 +162,40 @@ impl UserResult{
     /// Returns true if the user has a supporter animation, false if not in boolen format
     /// 
     /// ## Example:
-    /// ```rust
-    /// use speedrunapi::UserData;
-    /// let result = UserData::new("fishin_rod").run();
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, UserData};
+    /// let client = Client::new();
+    /// let result = UserData::new("fishin_rod").send(&client).await?;
     /// assert_eq!(result.supporter_animation(), false);
+    /// # Ok(())
+    /// # }
     /// ```
-    
     pub fn supporter_animation(&self) -> bool {
-        if let UserResult::User(user_data) = self {
-            user_data.data.supporter_animation
-        }
-        else{
-            panic!("Cannot Get animation from: {:?}", self);
-        }
+        self.supporter_animation
     }
 
     /// Returns the pronouns the user has listed on their profile
     /// 
     /// ## Returns:
     /// 
-    /// The pronouns the user has listed on their profile as a String
-    /// The pronouns can be "None" if the user has not selected any pronouns
+    /// The pronouns the user has listed on their profile as an Option<String>
+    /// The pronouns are None if the user has not selected any pronouns
     /// 
     /// ## Example:
-    /// ```rust
-    /// use speedrunapi::UserData;
-    /// let result = UserData::new("fishin_rod").run();
-    /// assert_eq!(result.pronouns(), "He/Him");
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, UserData};
+    /// let client = Client::new();
+    /// let result = UserData::new("fishin_rod").send(&client).await?;
+    /// assert_eq!(result.pronouns().as_deref(), Some("He/Him"));
+    /// # Ok(())
+    /// # }
     /// ```
-    
-    pub fn pronouns(&self) -> String {
-        if let UserResult::User(user_data) = self {
-            user_data.data.pronouns.clone().unwrap_or(String::from("None"))
-        }
-        else{
-            panic!("Cannot Get pronouns from: {:?}", self);
-        }
+    pub fn pronouns(&self) -> Option<String> {
+        self.pronouns.clone()
     }
 
     /// Returns the weblink of the user
@@ -164,19 +206,18 @@ impl UserResult{
     /// The weblink of the user as a String
     /// 
     /// ## Example:
-    /// ```rust
-    /// use speedrunapi::UserData;
-    /// let result = UserData::new("fishin_rod").run();
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, UserData};
+    /// let client = Client::new();
+    /// let result = UserData::new("fishin_rod").send(&client).await?;
     /// assert_eq!(result.weblink(), "https://www.speedrun.com/user/fishin_rod");
+    /// # Ok(())
+    /// # }
     /// ```
-    
     pub fn weblink(&self) -> String {
-        if let UserResult::User(user_data) = self {
-            user_data.data.weblink.clone()
-        }
-        else{
-            panic!("Cannot Get weblink from: {:?}", self);
-        }
+        self.weblink.clone()
     }
 
     /// Returns the role of the user
@@ -194,126 +235,118 @@ impl UserResult{
     /// - programer
     /// 
     /// ## Example:
-    /// ```rust
-    /// use speedrunapi::UserData;
-    /// let result = UserData::new("fishin_rod").run();
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, UserData};
+    /// let client = Client::new();
+    /// let result = UserData::new("fishin_rod").send(&client).await?;
     /// assert_eq!(result.role(), "user");
+    /// # Ok(())
+    /// # }
     /// ```
-    
     pub fn role(&self) -> String {
-        if let UserResult::User(user_data) = self {
-            user_data.data.role.clone()
-        }
-        else{
-            panic!("Cannot Get role from: {:?}", self);
-        }
+        self.role.clone()
     }
 
     /// Returns the date the user signed up for speedrun.com
     /// 
     /// ## Returns:
     /// 
-    /// The date the user signed up for speedrun.com as a String
+    /// The date the user signed up for speedrun.com as an Option<String>
     /// 
     /// **Notes:** 
-    /// - For older accounts the signup can be "None"
-    /// - The time is translated using the crates macro tl_time into a more user friendly format
+    /// - For older accounts the signup can be None
+    /// - The time is translated using the crates macro tl_time into a more user friendly format,
+    ///   a time in another format is returned as speedrun.com sent it
     /// 
     /// ## Example:
-    /// ```rust
-    /// use speedrunapi::UserData;
-    /// let result = UserData::new("fishin_rod").run();
-    /// assert_eq!(result.signup(), "2022-12-20 01:16:42");
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, UserData};
+    /// let client = Client::new();
+    /// let result = UserData::new("fishin_rod").send(&client).await?;
+    /// assert_eq!(result.signup().as_deref(), Some("2022-12-20 01:16:42"));
+    /// # Ok(())
+    /// # }
     /// ```
-     
-    pub fn signup(&self) -> String {
-        if let UserResult::User(user_data) = self {
-            let date = user_data.data.signup.clone().unwrap_or(String::from("None"));
-            if date != "None"{
-                let date = tl_time!(date);
-                return date;
-            } 
-            else{
-                return date;
-            }
-        }
-        else{
-            panic!("Cannot Get date from: {:?}", self);
-        }
+    pub fn signup(&self) -> Option<String> {
+        self.signup.as_deref().map(translate::time)
     }
 
     /// Returns the country the user is from
     /// 
     /// ## Arguments:
     /// 
-    /// `name_type: &str` - The format that you want the countrys name to be
+    /// `name_type: NameType` - The format that you want the countrys name to be
     /// 
     /// name_type can be:
-    /// - "international" : The countrys name in english 
-    /// - "japanese" : The countrys name in japanese can be "None"
-    /// - "code" : The code of the country
+    /// - NameType::International : The countrys name in english 
+    /// - NameType::Japanese : The countrys name in japanese can be None
+    /// - NameType::Code : The code of the country
     /// 
     /// ## Returns:
     /// 
-    /// The country in the type specified that the user is from as a String
+    /// The country in the type specified that the user is from as an Option<String>
+    /// The country is None if the user has not set where they are from
     /// 
     /// ## Example:
-    /// ```rust
-    /// use speedrunapi::UserData;
-    /// let result = UserData::new("fishin_rod").run();
-    /// assert_eq!(result.country("code"), "us");
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, UserData};
+    /// use speedrunapi::user_data::NameType;
+    /// let client = Client::new();
+    /// let result = UserData::new("fishin_rod").send(&client).await?;
+    /// assert_eq!(result.country(NameType::Code).as_deref(), Some("us"));
+    /// # Ok(())
+    /// # }
     /// ```
-
-    pub fn country(&self, name_type: &str) -> String {
-        if let UserResult::User(user_data) = self {
-            match name_type.to_lowercase().as_str() {
-                "code" => user_data.data.location.country.code.clone(),
-                "international" => user_data.data.location.country.names.international.clone(),
-                "japanese" => user_data.data.location.country.names.japanese.clone().unwrap_or(String::from("None")),
-                _ => panic!("Invalid type: {:?}", name_type),
-            } 
-        }
-        else{
-            panic!("Cannot Get country from: {:?}", self);
-        }
+    pub fn country(&self, name_type: NameType) -> Option<String> {
+        let country = &self.location.as_ref()?.country;
+        match name_type {
+            NameType::Code => Some(country.code.clone()),
+            NameType::International => Some(country.names.international.clone()),
+            NameType::Japanese => country.names.japanese.clone(),
+        } 
     }
 
     /// Returns the region the user is from
     /// 
     /// ## Arguments:
     /// 
-    /// `name_type: &str` - The format that you want the regions name to be
+    /// `name_type: NameType` - The format that you want the regions name to be
     /// 
     /// name_type can be:
-    /// - "international" : The regions name in english 
-    /// - "japanese" : The regions name in japanese can be "None"
-    /// - "code" : The code of the region
+    /// - NameType::International : The regions name in english 
+    /// - NameType::Japanese : The regions name in japanese can be None
+    /// - NameType::Code : The code of the region
     /// 
     /// ## Returns:
     /// 
-    /// The region in the type specified that the user is from as a String
+    /// The region in the type specified that the user is from as an Option<String>
+    /// The region is None if the user has not set one
     /// 
     /// ## Example:
-    /// ```rust
-    /// use speedrunapi::UserData;
-    /// let result = UserData::new("fishin_rod").run();
-    /// assert_eq!(result.region("code"), "us/co");
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, UserData};
+    /// use speedrunapi::user_data::NameType;
+    /// let client = Client::new();
+    /// let result = UserData::new("fishin_rod").send(&client).await?;
+    /// assert_eq!(result.region(NameType::Code).as_deref(), Some("us/co"));
+    /// # Ok(())
+    /// # }
     /// ```
-    
-    pub fn region(&self, name_type: &str) -> String {
-        if let UserResult::User(user_data) = self {
-            match name_type.to_lowercase().as_str() {
-                "code" => user_data.data.location.region.as_ref().map(|code| code.code.clone())
-                .unwrap_or(String::from("None")),
-                "international" => user_data.data.location.region.as_ref().map(|name| name.names.international.clone())
-                .unwrap_or(String::from("None")),
-                "japanese" => user_data.data.location.region.as_ref().map(|name| name.names.japanese.clone()
-                .unwrap_or(String::from("None"))).unwrap_or(String::from("None")),
-                _ => panic!("Invalid type: {:?}", name_type),
-            }   
-        } else{
-            panic!("Cannot Get region from: {:?}", self);
-        }
+    pub fn region(&self, name_type: NameType) -> Option<String> {
+        let region = self.location.as_ref()?.region.as_ref()?;
+        match name_type {
+            NameType::Code => Some(region.code.clone()),
+            NameType::International => Some(region.names.international.clone()),
+            NameType::Japanese => region.names.japanese.clone(),
+        }   
     }
 
     /// Returns the links the user has listed on their profile
@@ -321,42 +354,42 @@ impl UserResult{
     /// ## Arguments:
     /// 
     /// You must provide the type of link you want in the function arguments
-    /// The link must be an &str
+    /// The link must be a Service
     /// 
     /// The type can be:
-    /// - twitch
-    /// - hitbox
-    /// - youtube
-    /// - twitter
-    /// - speedrunslive
+    /// - Service::Twitch
+    /// - Service::Hitbox
+    /// - Service::Youtube
+    /// - Service::Twitter
+    /// - Service::Speedrunslive
     /// 
-    /// If none are provided you will get and error,
-    /// if you provide a type that the user doenst have the function will return "None"
+    /// If you provide a type that the user doenst have the function will return None
     /// 
     /// ## Returns:
     /// 
-    /// The link chosen of the user as a String
+    /// The link chosen of the user as an Option<String>
     /// 
     /// ## Example:
-    /// ```rust
-    /// use speedrunapi::UserData;
-    /// let result = UserData::new("fishin_rod").run();
-    /// assert_eq!(result.links("twitch"), "None");
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, UserData};
+    /// use speedrunapi::user_data::Service;
+    /// let client = Client::new();
+    /// let result = UserData::new("fishin_rod").send(&client).await?;
+    /// assert_eq!(result.links(Service::Twitch), None);
+    /// # Ok(())
+    /// # }
     /// ```
-    
-    pub fn links(&self, service: &str) -> String {
-        if let UserResult::User(user_data) = self {
-            match service.to_lowercase().as_str() {
-                "twitch" => user_data.data.twitch.clone().unwrap_or(String::from("None")),
-                "hitbox" => user_data.data.hitbox.clone().unwrap_or(String::from("None")),
-                "youtube" => user_data.data.youtube.clone().unwrap_or(String::from("None")),
-                "twitter" => user_data.data.twitter.clone().unwrap_or(String::from("None")),
-                "speedrunslive" => user_data.data.speedrunslive.clone().unwrap_or(String::from("None")),
-                _ => panic!("Invalid Service: {}", service),
-            }
-        } else{
-            panic!("Cannot Get links from: {:?}", self);
-        }
+    pub fn links(&self, service: Service) -> Option<String> {
+        let link = match service {
+            Service::Twitch => &self.twitch,
+            Service::Hitbox => &self.hitbox,
+            Service::Youtube => &self.youtube,
+            Service::Twitter => &self.twitter,
+            Service::Speedrunslive => &self.speedrunslive,
+        };
+        link.as_ref().and_then(|link| link.uri.clone())
     }
     
     /// Returns the assets the user has on their profile
@@ -364,37 +397,36 @@ impl UserResult{
     /// ## Arguments:
     /// 
     /// You must provide the type of asset you want in the function arguments
-    /// The asset must be an &str
+    /// The asset must be a UserAsset
     /// 
     /// The type can be:
-    /// - icon
-    /// - supporter_icon
-    /// - image
+    /// - UserAsset::Icon
+    /// - UserAsset::SupporterIcon
+    /// - UserAsset::Image
     /// 
-    /// If none are provided you will get and error,
-    /// if you provide a type that the user doenst have the function will return "None"
+    /// If you provide a type that the user doenst have the function will return None
     /// 
     /// ## Returns:
     /// 
-    /// The asset chosen of the user as a String
+    /// The asset chosen of the user as an Option<String>
     /// 
     /// ## Example:
-    /// ```rust
-    /// use speedrunapi::UserData;
-    /// let result = UserData::new("fishin_rod").run();
-    /// assert_eq!(result.assets("icon"), "None");
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, UserData};
+    /// use speedrunapi::user_data::UserAsset;
+    /// let client = Client::new();
+    /// let result = UserData::new("fishin_rod").send(&client).await?;
+    /// assert_eq!(result.assets(UserAsset::Icon), None);
+    /// # Ok(())
+    /// # }
     /// ```
-    
-    pub fn assets(&self, asset: &str) -> String{
-        if let UserResult::User(user_data) = self {
-            match asset.to_lowercase().as_str() {
-                "icon" => user_data.data.assets.icon.uri.clone().unwrap_or(String::from("None")),
-                "supporter_icon" => user_data.data.assets.supporter_icon.as_ref().map(|link| link.uri.clone()).flatten().unwrap_or(String::from("None")),
-                "image" => user_data.data.assets.image.uri.clone().unwrap_or(String::from("None")),
-                _ => panic!("Invalid Asset: {}", asset),
-            }
-        } else{
-            panic!("Cannot Get assets from: {:?}", self);
+    pub fn assets(&self, asset: UserAsset) -> Option<String>{
+        match asset {
+            UserAsset::Icon => self.assets.icon.uri.clone(),
+            UserAsset::SupporterIcon => self.assets.supporter_icon.as_ref().and_then(|link| link.uri.clone()),
+            UserAsset::Image => self.assets.image.uri.clone(),
         }
     }
 
@@ -405,22 +437,22 @@ impl UserResult{
     /// The links to other apis about the user as a Vec<(String, String)>
     /// 
     /// ## Example:
-    /// ```rust
-    /// use speedrunapi::UserData;
-    /// let result = UserData::new("fishin_rod").run();
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, UserData};
+    /// let client = Client::new();
+    /// let result = UserData::new("fishin_rod").send(&client).await?;
     /// println!("{:?}", result.user_links());
+    /// # Ok(())
+    /// # }
     /// ```
-    
     pub fn user_links(&self) -> Vec<(String, String)> {
-        if let UserResult::User(user_data) = self {
-            let links = &user_data.data.links;
-            let names: Vec<&str> = links.iter().map(|link| link.rel.as_str()).collect();
-            let urls: Vec<&str> = links.iter().map(|link| link.uri.as_str()).collect();
-            let merged_vec: Vec<(String, String)> = names.iter().zip(urls.iter()).map(|(x, y)| (x.to_string(), y.to_string())).collect();
-            return merged_vec.to_vec();
-        } else{
-            panic!("Cannot Get links from: {:?}", self);
-        }
+        let links = &self.links;
+        let names: Vec<&str> = links.iter().map(|link| link.rel.as_str()).collect();
+        let urls: Vec<&str> = links.iter().map(|link| link.uri.as_str()).collect();
+        let merged_vec: Vec<(String, String)> = names.iter().zip(urls.iter()).map(|(x, y)| (x.to_string(), y.to_string())).collect();
+        merged_vec
     }
 }
 
@@ -442,24 +474,65 @@ impl UserData{
     /// let result = UserData::new("fishin_rod");
     /// println!("{:?}", result);
     /// ```
-    /// This will print the parameters passed to the function, to print the data you must add .run() to the code:
-    /// ```rust
-    /// use speedrunapi::UserData;
-    /// let result = UserData::new("fishin_rod").run();
+    /// This will print the parameters passed to the function, to print the data you must add .send(&client).await to the code:
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, UserData};
+    /// let client = Client::new();
+    /// let result = UserData::new("fishin_rod").send(&client).await?;
     /// println!("{:?}", result);
+    /// # Ok(())
+    /// # }
     /// ```
-    
     pub fn new(name: &str) -> UserData{
         UserData{
             name: name.to_string(),
-            personal_bests: false,
         }
     }
 
-    /// Does nothing for now but will swich embed to get categories later
-    pub fn personal_bests(mut self) -> Self{
-        self.personal_bests = true;
-        self
+    /// Creates a request for a list of users
+    /// 
+    /// speedrun.com only returns users when at least one filter is set.
+    /// 
+    /// ## Examples:
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, UserData};
+    /// let client = Client::new();
+    /// let result = UserData::list().name("fishin").send(&client).await?;
+    /// for user in result{
+    ///     println!("{}", user.name());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// This will print every user with "fishin" in their name.
+    pub fn list() -> UserSearch{
+        UserSearch::new()
+    }
+
+    /// Creates a request for the personal bests of the user
+    /// 
+    /// The personal bests can be filtered with [`PersonalBests::top`], [`PersonalBests::series`] and [`PersonalBests::game`].
+    /// 
+    /// ## Examples:
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, UserData};
+    /// let client = Client::new();
+    /// let result = UserData::new("fishin_rod").personal_bests().top(3).send(&client).await?;
+    /// for pb in result{
+    ///     println!("{} {}: {:?}", pb.place, pb.category(), pb.run.time());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// This will print every personal best of the user that is in the top 3 of its leaderboard.
+    pub fn personal_bests(&self) -> PersonalBests{
+        PersonalBests::new(&self.name)
     }
 
     /// Makes the request to the speedrun.com API
@@ -470,41 +543,86 @@ impl UserData{
     /// 
     /// ## Returns:
     /// 
-    /// The result of the request as a UserResult
+    /// The user, or the [`Error`] if the request failed or the user could not be found
     /// 
     /// ## Example:
-    /// ```rust
-    /// use speedrunapi::UserData;
-    /// let result = UserData::new("fishin_rod").run();
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, UserData};
+    /// let client = Client::new();
+    /// let result = UserData::new("fishin_rod").send(&client).await?;
     /// println!("{:?}", result);
+    /// # Ok(())
+    /// # }
     /// ```
     /// This will print the data of the user you are seraching for
-    
-    #[tokio::main]
-    pub async fn run(&self) -> UserResult{
-        let client = reqwest::Client::new();
-        let url = if self.personal_bests{
-            format!("https://www.speedrun.com/api/v1/users/{}/personal_bests", self.name)
-        } else{
-            format!("https://www.speedrun.com/api/v1/users/{}", self.name)
-        };
-        let response = match client.get(url).send().await{
-            Ok(response) => response,
-            Err(err) => return UserResult::Error(UserError::ReqwestError(err.into())),
+    pub async fn send(&self, client: &Client) -> Result<User, Error>{
+        let path = format!("/users/{}", self.name);
+        let response = client.get_json::<Data<User>>(&path).await?;
+        Ok(response.data)
+    }
+
+    Blocking_Function!(Result<User, Error>);
+}
+
+impl PersonalBest{
+
+    /// Returns the id of the game the personal best is for
+    pub fn game(&self) -> &str{
+        &self.run.game
+    }
+
+    /// Returns the id of the category the personal best is for
+    pub fn category(&self) -> &str{
+        &self.run.category
+    }
+
+    /// Returns the id of the level the personal best is for, or None for full game runs
+    pub fn level(&self) -> Option<&str>{
+        self.run.level.as_deref()
+    }
+}
+
+impl PersonalBests{
+
+    /// Only returns the personal bests that are in the top places of their leaderboard
+    pub fn top(mut self, top: u32) -> Self{
+        self.query.set("top", &top.to_string());
+        self
+    }
+
+    Filter_Function!("Only returns the personal bests for games in the series with this id or abbreviation", series, "series");
+    Filter_Function!("Only returns the personal bests for the game with this id or abbreviation", game, "game");
+
+    Embed_Function!(PersonalBestEmbed);
+}
+
+impl UserSearch{
+
+    Filter_Function!("Only returns the user whose name or linked account name is exactly this one, ignoring case", lookup, "lookup");
+    Filter_Function!("Only returns the users with this text in their name", name, "name");
+    Filter_Function!("Only returns the user with this twitch account", twitch, "twitch");
+    Filter_Function!("Only returns the user with this hitbox account", hitbox, "hitbox");
+    Filter_Function!("Only returns the user with this twitter account", twitter, "twitter");
+    Filter_Function!("Only returns the user with this speedrunslive account", speedrunslive, "speedrunslive");
+
+    /// Sorts the users
+    /// 
+    /// # Arguments:
+    /// 
+    /// `order: UserOrder` - What to sort the users by
+    /// 
+    /// `direction: Direction` - Which way to sort them
+    pub fn order(mut self, order: UserOrder, direction: Direction) -> Self{
+        let order = match order{
+            UserOrder::Name => "name.int",
+            UserOrder::JapaneseName => "name.jap",
+            UserOrder::Signup => "signup",
+            UserOrder::Role => "role",
         };
-        if response.status() == reqwest::StatusCode::NOT_FOUND{
-            return UserResult::Error(UserError::UserNotFound);
-        }
-        if self.personal_bests{
-            println!("Test");
-            UserResult::None
-        }
-        else{
-            let response = match response.json::<Data>().await{
-                Ok(response) => response,
-                Err(err) => return UserResult::Error(UserError::ReqwestError(err.into())),
-            };
-            UserResult::User(response)
-        }
+        self.query.set("orderby", order);
+        self.query.set("direction", direction.as_str());
+        self
     }
 }
@@ -0,0 +1,126 @@
+//! Variables
+//! 
+//! This module provides data about variables on speedrun.com
+//! 
+//! Variables are the extra details a run can have, such as the version of the game or the difficulty.
+//! Some variables are subcategories, where every value gets its own leaderboard, like on the website.
+//! 
+//! # Arguments:
+//! 
+//! Create the request with VariableData::new("variable_id") and call .send(&client).await with a [`Client`](crate::Client).
+//! 
+//! # Examples:
+//! 
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), speedrunapi::Error> {
+//! use speedrunapi::{Client, VariableData};
+//! let client = Client::new();
+//! let result = VariableData::new("jlzkwql2").send(&client).await?;
+//! if result.is_subcategory{
+//!     for (id, value) in &result.values.values{
+//!         println!("{}: {}", id, value.label);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//! This will print every value of the variable if it is a subcategory.
+
+use crate::types::Data;
+use crate::types::{Variable, VariableScope};
+use crate::{Client, Error};
+
+#[derive(Debug)]
+pub struct VariableData{
+    pub id: String,
+}
+
+impl Variable{
+
+    /// Returns the label of a value of the variable
+    /// 
+    /// ## Arguments:
+    /// 
+    /// `value: &str` - The id of the value, such as the ids in [`Run::values`](crate::Run::values)
+    /// 
+    /// ## Returns:
+    /// 
+    /// The label as an Option<&str>, None if the variable does not have the value
+    /// 
+    /// ## Example:
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, VariableData};
+    /// let client = Client::new();
+    /// let result = VariableData::new("jlzkwql2").send(&client).await?;
+    /// assert_eq!(result.label("mln68v0q"), Some("1.16+"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn label(&self, value: &str) -> Option<&str>{
+        self.values.values.get(value).map(|value| value.label.as_str())
+    }
+
+    /// Returns true if the variable can be used by runs of the level, or by full game runs if the level is None
+    /// 
+    /// ## Arguments:
+    /// 
+    /// `level: Option<&str>` - The id of the level, or None for the full game
+    pub fn applies_to(&self, level: Option<&str>) -> bool{
+        match (&self.scope, level){
+            (VariableScope::Global, _) => true,
+            (VariableScope::FullGame, None) => true,
+            (VariableScope::AllLevels, Some(_)) => true,
+            (VariableScope::SingleLevel{ level: scope }, Some(level)) => scope == level,
+            _ => false,
+        }
+    }
+}
+
+impl VariableData{
+
+    /// Creates a new VariableData object
+    /// 
+    /// # Arguments:
+    /// 
+    /// `id: &str` - The id of the variable
+    /// 
+    /// # Examples:
+    /// ```rust
+    /// use speedrunapi::VariableData;
+    /// let result = VariableData::new("jlzkwql2");
+    /// println!("{:?}", result);
+    /// ```
+    pub fn new(id: &str) -> VariableData{
+        VariableData{
+            id: id.to_string(),
+        }
+    }
+
+    /// Runs the request to the speedrun.com API
+    /// 
+    /// # Returns:
+    /// 
+    /// The variable, or the [`Error`] if the request failed or the variable could not be found
+    /// 
+    /// # Example:
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), speedrunapi::Error> {
+    /// use speedrunapi::{Client, VariableData};
+    /// let client = Client::new();
+    /// let result = VariableData::new("jlzkwql2").send(&client).await?;
+    /// println!("{:?}", result);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send(&self, client: &Client) -> Result<Variable, Error>{
+        let path = format!("/variables/{}", self.id);
+        let response = client.get_json::<Data<Variable>>(&path).await?;
+        Ok(response.data)
+    }
+
+    Blocking_Function!(Result<Variable, Error>);
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "blocking")]
+
+mod common;
+
+use speedrunapi::{Client, GameTypeData};
+use tokio::runtime::Runtime;
+use wiremock::MockServer;
+
+/// Starts the stand-in on a runtime of its own, the requests are then sent without one
+fn stand_in() -> (Runtime, MockServer, Client){
+    let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+    let (server, client) = runtime.block_on(async {
+        let (server, client) = common::stand_in().await;
+        common::serve(&server, "/gametypes/Fangame", "gametype").await;
+        common::serve(&server, "/gametypes/d91jd1ex", "gametype").await;
+        (server, client)
+    });
+    (runtime, server, client)
+}
+
+#[test]
+fn name(){
+    let (_runtime, _server, client) = stand_in();
+    let result = GameTypeData::new("Fangame").run(&client).unwrap();
+    assert_eq!(result.name(), "Fangame");
+}
+
+#[test]
+fn reuse(){
+    // Both requests wait on the same shared runtime
+    let (_runtime, _server, client) = stand_in();
+    let first = GameTypeData::new("Fangame").run(&client).unwrap();
+    let second = GameTypeData::new("d91jd1ex").run(&client).unwrap();
+    assert_eq!(first.id(), second.id());
+}
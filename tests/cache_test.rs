@@ -0,0 +1,96 @@
+mod common;
+
+use std::time::Duration;
+use speedrunapi::cache::Resource;
+use speedrunapi::{Cache, GameTypeData, LeaderboardData, PlatformData};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Answers the path with the fixture, expecting exactly this many requests
+async fn serve(server: &MockServer, route: &str, name: &str, times: u64){
+    Mock::given(method("GET"))
+        .and(path(route))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(common::fixture(name), "application/json"))
+        .expect(times)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn hit(){
+    let (server, client) = common::stand_in_with(|builder| builder.cache(Cache::new(Duration::from_secs(60)))).await;
+    serve(&server, "/gametypes/Fangame", "gametype", 1).await;
+    let first = GameTypeData::new("Fangame").send(&client).await.unwrap();
+    let second = GameTypeData::new("Fangame").send(&client.clone()).await.unwrap();
+    assert_eq!(first, second);
+    assert_eq!(client.cache().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn query_order(){
+    let (server, client) = common::stand_in_with(|builder| builder.cache(Cache::new(Duration::from_secs(60)))).await;
+    serve(&server, "/leaderboards/j1npme6p/category/mkeyl926", "leaderboard", 1).await;
+    LeaderboardData::new("j1npme6p", "mkeyl926").top(3).video_only(true).send(&client).await.unwrap();
+    LeaderboardData::new("j1npme6p", "mkeyl926").video_only(true).top(3).send(&client).await.unwrap();
+}
+
+#[tokio::test]
+async fn ttl(){
+    let cache = Cache::new(Duration::from_secs(60))
+        .ttl(Resource::GameTypes, Duration::from_millis(100))
+        .ttl(Resource::Platforms, Duration::ZERO);
+    let (server, client) = common::stand_in_with(|builder| builder.cache(cache)).await;
+    serve(&server, "/gametypes/Fangame", "gametype", 2).await;
+    serve(&server, "/platforms/8gej2n93", "platform", 2).await;
+    GameTypeData::new("Fangame").send(&client).await.unwrap();
+    GameTypeData::new("Fangame").send(&client).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    GameTypeData::new("Fangame").send(&client).await.unwrap();
+
+    // Platforms are not cached at all
+    PlatformData::new("8gej2n93").send(&client).await.unwrap();
+    PlatformData::new("8gej2n93").send(&client).await.unwrap();
+}
+
+#[tokio::test]
+async fn invalidate(){
+    let (server, client) = common::stand_in_with(|builder| builder.cache(Cache::new(Duration::from_secs(60)))).await;
+    serve(&server, "/gametypes/Fangame", "gametype", 3).await;
+    serve(&server, "/platforms/8gej2n93", "platform", 2).await;
+    let cache = client.cache().unwrap();
+    GameTypeData::new("Fangame").send(&client).await.unwrap();
+    cache.invalidate("/gametypes/Fangame/");
+    GameTypeData::new("Fangame").send(&client).await.unwrap();
+
+    PlatformData::new("8gej2n93").send(&client).await.unwrap();
+    cache.invalidate_resource(Resource::GameTypes);
+    assert_eq!(cache.len(), 1);
+    GameTypeData::new("Fangame").send(&client).await.unwrap();
+
+    cache.clear();
+    assert!(cache.is_empty());
+    PlatformData::new("8gej2n93").send(&client).await.unwrap();
+}
+
+#[tokio::test]
+async fn invalidate_url(){
+    let (server, client) = common::stand_in_with(|builder| builder.cache(Cache::new(Duration::from_secs(60)))).await;
+    serve(&server, "/gametypes/Fangame", "gametype", 2).await;
+    GameTypeData::new("Fangame").send(&client).await.unwrap();
+    client.cache().unwrap().invalidate(&format!("{}/gametypes/Fangame", server.uri()));
+    GameTypeData::new("Fangame").send(&client).await.unwrap();
+}
+
+#[tokio::test]
+async fn errors(){
+    let (server, client) = common::stand_in_with(|builder| builder.cache(Cache::new(Duration::from_secs(60)))).await;
+    Mock::given(method("GET"))
+        .and(path("/gametypes/Fangame"))
+        .respond_with(ResponseTemplate::new(404).set_body_string(r#"{"status": 404, "message": "Not found"}"#))
+        .expect(2)
+        .mount(&server)
+        .await;
+    assert!(GameTypeData::new("Fangame").send(&client).await.is_err());
+    assert!(GameTypeData::new("Fangame").send(&client).await.is_err());
+    assert!(client.cache().unwrap().is_empty());
+}
@@ -0,0 +1,35 @@
+mod common;
+
+use speedrunapi::types::{CategoryType, Players};
+use speedrunapi::CategoryData;
+
+#[tokio::test]
+async fn lookup(){
+    let (server, client) = common::stand_in().await;
+    common::serve(&server, "/categories/9d8v96lk", "category").await;
+    let result = CategoryData::new("9d8v96lk").send(&client).await.unwrap();
+    assert_eq!(result.category_type, CategoryType::PerGame);
+    assert_eq!(result.players, Players::UpTo(4));
+    assert!(result.miscellaneous);
+    assert!(!result.is_per_level());
+    assert!(!result.is_solo());
+    assert_eq!(result.max_players(), 4);
+}
+
+#[tokio::test]
+async fn sub_resources(){
+    let (server, client) = common::stand_in().await;
+    common::serve(&server, "/categories/mkeyl926/variables", "variables").await;
+    common::serve(&server, "/categories/mkeyl926/records", "records").await;
+    let category = CategoryData::new("mkeyl926");
+    assert_eq!(category.variables().send(&client).await.unwrap().len(), 2);
+    assert_eq!(category.records().send(&client).await.unwrap()[0].game, "j1npme6p");
+}
+
+#[test]
+fn serialize(){
+    let json = common::fixture("category");
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    let category: speedrunapi::Category = serde_json::from_value(value["data"].clone()).unwrap();
+    assert_eq!(serde_json::to_value(&category).unwrap(), value["data"]);
+}
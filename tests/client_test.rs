@@ -0,0 +1,34 @@
+mod common;
+
+use std::time::Duration;
+use speedrunapi::{Client, GameTypeData};
+
+#[test]
+fn build(){
+    let client = Client::builder()
+        .user_agent("speedrunapi-tests")
+        .timeout(Duration::from_secs(5))
+        .build();
+    assert!(client.is_ok());
+}
+
+#[tokio::test]
+async fn inside_runtime(){
+    // Creating and cloning a client inside a runtime must not panic
+    let client = Client::new();
+    let _clone = client.clone();
+}
+
+#[tokio::test]
+async fn base_url(){
+    let (server, client) = common::stand_in().await;
+    common::serve(&server, "/gametypes/Fangame", "gametype").await;
+    let result = GameTypeData::new("Fangame").send(&client).await.unwrap();
+    assert_eq!(result.id(), "d91jd1ex");
+}
+
+#[test]
+fn trailing_slash(){
+    let client = Client::builder().base_url("http://localhost:8080/api/v1/").build().unwrap();
+    assert_eq!(client.base_url(), "http://localhost:8080/api/v1");
+}
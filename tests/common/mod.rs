@@ -0,0 +1,32 @@
+#![allow(dead_code)]
+
+use speedrunapi::{Client, ClientBuilder};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Starts a local stand-in for speedrun.com and a client that sends its requests to it
+pub async fn stand_in() -> (MockServer, Client){
+    stand_in_with(|builder| builder).await
+}
+
+/// Starts a local stand-in for speedrun.com and a client that sends its requests to it, with more settings on the client
+pub async fn stand_in_with(settings: impl FnOnce(ClientBuilder) -> ClientBuilder) -> (MockServer, Client){
+    let server = MockServer::start().await;
+    let client = settings(Client::builder().base_url(&server.uri())).build().unwrap();
+    (server, client)
+}
+
+/// Reads a JSON response from the fixtures folder
+pub fn fixture(name: &str) -> String{
+    let file = format!("{}/tests/fixtures/{}.json", env!("CARGO_MANIFEST_DIR"), name);
+    std::fs::read_to_string(&file).unwrap_or_else(|_| panic!("Missing fixture: {}", file))
+}
+
+/// Makes the stand-in server answer GET requests for the path with the fixture
+pub async fn serve(server: &MockServer, route: &str, name: &str){
+    Mock::given(method("GET"))
+        .and(path(route))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(fixture(name), "application/json"))
+        .mount(server)
+        .await;
+}
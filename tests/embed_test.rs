@@ -0,0 +1,174 @@
+mod common;
+
+use serde_json::{json, Value};
+use speedrunapi::embed::{CategoryEmbed, GameEmbed, PersonalBestEmbed, RunEmbed};
+use speedrunapi::types::{EmbeddedPlayer, Player};
+use speedrunapi::{Error, Game, GameData, RunData, UserData};
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+/// Reads the data of a fixture
+fn data(name: &str) -> Value{
+    let fixture: Value = serde_json::from_str(&common::fixture(name)).unwrap();
+    fixture["data"].clone()
+}
+
+/// Returns the game fixture with its categories, their variables, its platforms and its moderators embedded
+fn embedded_game() -> Value{
+    let mut category = data("category");
+    category["variables"] = json!({ "data": data("variables") });
+    let mut game = data("game");
+    game["categories"] = json!({ "data": [category] });
+    game["platforms"] = json!({ "data": [data("platform")] });
+    game["moderators"] = json!({ "data": [data("user")] });
+    game
+}
+
+#[tokio::test]
+async fn game(){
+    let (server, client) = common::stand_in().await;
+    Mock::given(method("GET"))
+        .and(path("/games/mc"))
+        .and(query_param("embed", "categories.variables,platforms,moderators"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "data": embedded_game() })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let game = GameData::new("mc")
+        .embed(GameEmbed::Categories.with(CategoryEmbed::Variables))
+        .embed(GameEmbed::Platforms)
+        .embed(GameEmbed::Moderators)
+        .send(&client)
+        .await
+        .unwrap();
+
+    let categories = game.embedded.categories.as_ref().unwrap();
+    assert_eq!(categories[0].id, data("category")["id"]);
+    assert_eq!(categories[0].embedded.variables.as_ref().unwrap().len(), 2);
+    assert_eq!(game.embedded.platforms.as_ref().unwrap()[0].name, "PC");
+    assert!(game.embedded.levels.is_none());
+
+    // The ids stay where they were
+    assert_eq!(game.platforms, Some(vec!["8gej2n93".to_string()]));
+    let moderator = game.embedded.moderators.as_ref().unwrap()[0].id.clone();
+    assert_eq!(game.moderators.get(&moderator), Some(&None));
+    assert!(game.moderators().contains(&(moderator, None)));
+}
+
+#[tokio::test]
+async fn run(){
+    let (server, client) = common::stand_in().await;
+    let mut user = data("user");
+    user["rel"] = "user".into();
+    let mut guest = data("guest");
+    guest["rel"] = "guest".into();
+    let mut run = data("run");
+    run["players"] = json!({ "data": [user, guest] });
+    run["game"] = json!({ "data": data("game") });
+    run["level"] = json!({ "data": [] });
+    Mock::given(method("GET"))
+        .and(path("/runs/90y6pm7e"))
+        .and(query_param("embed", "players,game,level"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "data": run })))
+        .mount(&server)
+        .await;
+    let run = RunData::new("90y6pm7e")
+        .embed(RunEmbed::Players)
+        .embed(RunEmbed::Game)
+        .embed(RunEmbed::Level)
+        .send(&client)
+        .await
+        .unwrap();
+
+    assert_eq!(run.game, data("game")["id"]);
+    assert_eq!(run.embedded.game.as_ref().unwrap().id, run.game);
+    assert_eq!(run.level, None);
+    assert_eq!(run.embedded.level, None);
+
+    let players = run.embedded.players.as_ref().unwrap();
+    match (&players[0], &run.players[0]){
+        (EmbeddedPlayer::User(user), Player::User{ id, .. }) => assert_eq!(&user.id, id),
+        players => panic!("Expected a user, got: {:?}", players),
+    }
+    match (&players[1], &run.players[1]){
+        (EmbeddedPlayer::Guest(guest), Player::Guest{ name, .. }) => assert_eq!(&guest.name, name),
+        players => panic!("Expected a guest, got: {:?}", players),
+    }
+}
+
+#[tokio::test]
+async fn personal_best(){
+    let (server, client) = common::stand_in().await;
+    let mut user = data("user");
+    user["rel"] = "user".into();
+    let mut best = data("personal_bests")[0].clone();
+    best["game"] = json!({ "data": data("game") });
+    best["level"] = json!({ "data": [] });
+    best["players"] = json!({ "data": [user] });
+    best["platform"] = json!({ "data": data("platform") });
+    Mock::given(method("GET"))
+        .and(path("/users/fishin_rod/personal-bests"))
+        .and(query_param("embed", "game.categories,level,players,platform"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "data": [best] })))
+        .mount(&server)
+        .await;
+    let result = UserData::new("fishin_rod")
+        .personal_bests()
+        .embed(PersonalBestEmbed::Game.with(GameEmbed::Categories))
+        .embed(PersonalBestEmbed::Level)
+        .embed(PersonalBestEmbed::Players)
+        .embed(PersonalBestEmbed::Platform)
+        .send(&client)
+        .await
+        .unwrap();
+
+    // The embedded resources are next to the run, which stays the same
+    let best = &result[0];
+    assert_eq!(best.run.id, data("personal_bests")[0]["run"]["id"]);
+    assert_eq!(best.embedded.game.as_ref().unwrap().id, best.run.game);
+    assert_eq!(best.embedded.level, None);
+    assert_eq!(best.embedded.platform.as_ref().unwrap().name, "PC");
+    match &best.embedded.players.as_ref().unwrap()[0]{
+        EmbeddedPlayer::User(user) => assert_eq!(user.id, data("user")["id"]),
+        player => panic!("Expected a user, got: {:?}", player),
+    }
+    assert!(best.embedded.region.is_none());
+}
+
+#[test]
+fn serialize(){
+    let game: Game = serde_json::from_value(embedded_game()).unwrap();
+    let json = serde_json::to_string(&game).unwrap();
+    assert_eq!(serde_json::from_str::<Game>(&json).unwrap(), game);
+
+    // Nothing is added when nothing was embedded
+    let game: Game = serde_json::from_value(data("game")).unwrap();
+    assert!(serde_json::to_value(&game).unwrap().get("embedded").is_none());
+}
+
+#[tokio::test]
+async fn decode_path(){
+    let (server, client) = common::stand_in().await;
+    let mut game = embedded_game();
+    game["released"] = json!("soon");
+    game["categories"]["data"][0]["name"] = json!(5);
+    Mock::given(path("/games/mc"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "data": game })))
+        .mount(&server)
+        .await;
+
+    // A field that is not embedded keeps its full path
+    let result = GameData::new("mc").send(&client).await;
+    match result{
+        Err(err @ Error::Decode{ .. }) => assert!(err.to_string().contains("`data.released`"), "{}", err),
+        result => panic!("Expected Decode, got: {:?}", result),
+    }
+
+    // An embedded resource has its path in the message
+    game["released"] = json!(2011);
+    let result = serde_json::from_value::<Game>(game);
+    match result{
+        Err(err) => assert!(err.to_string().contains("`embedded.categories[0].name`"), "{}", err),
+        result => panic!("Expected an error, got: {:?}", result),
+    }
+}
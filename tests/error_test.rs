@@ -0,0 +1,67 @@
+mod common;
+
+use speedrunapi::{Error, GameTypeData, GuestData};
+use wiremock::matchers::path;
+use wiremock::{Mock, ResponseTemplate};
+
+#[tokio::test]
+async fn not_found(){
+    let (server, client) = common::stand_in().await;
+    Mock::given(path("/guests/Alex"))
+        .respond_with(ResponseTemplate::new(404).set_body_string(r#"{"status": 404, "message": "Guest not found."}"#))
+        .mount(&server)
+        .await;
+    let result = GuestData::new("Alex").send(&client).await;
+    match result{
+        Err(Error::NotFound{ message }) => assert_eq!(message, "Guest not found."),
+        result => panic!("Expected NotFound, got: {:?}", result),
+    }
+}
+
+#[tokio::test]
+async fn rate_limited(){
+    let (server, client) = common::stand_in().await;
+    Mock::given(path("/guests/Alex"))
+        .respond_with(ResponseTemplate::new(420).insert_header("Retry-After", "30"))
+        .mount(&server)
+        .await;
+    let result = GuestData::new("Alex").send(&client).await;
+    match result{
+        Err(Error::RateLimited{ retry_after }) => assert_eq!(retry_after, Some(std::time::Duration::from_secs(30))),
+        result => panic!("Expected RateLimited, got: {:?}", result),
+    }
+}
+
+#[tokio::test]
+async fn server(){
+    let (server, client) = common::stand_in().await;
+    Mock::given(path("/gametypes/Fangame"))
+        .respond_with(ResponseTemplate::new(503).set_body_string(r#"{"status": 503, "message": "Service unavailable."}"#))
+        .mount(&server)
+        .await;
+    let result = GameTypeData::new("Fangame").send(&client).await;
+    match result{
+        Err(Error::Server{ status, message }) => {
+            assert_eq!(status.as_u16(), 503);
+            assert_eq!(message, "Service unavailable.");
+        }
+        result => panic!("Expected Server, got: {:?}", result),
+    }
+}
+
+#[tokio::test]
+async fn decode(){
+    let (server, client) = common::stand_in().await;
+    Mock::given(path("/gametypes/Fangame"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"data": {"id": "d91jd1ex", "name": 5}}"#))
+        .mount(&server)
+        .await;
+    let result = GameTypeData::new("Fangame").send(&client).await;
+    match result{
+        Err(err @ Error::Decode{ .. }) => {
+            assert!(err.to_string().contains("data.name"));
+            assert!(std::error::Error::source(&err).is_some());
+        }
+        result => panic!("Expected Decode, got: {:?}", result),
+    }
+}
@@ -1,8 +1,172 @@
-use speedrunapi::GameData;
+mod common;
 
-#[test]
-fn print(){
-    let result = GameData::new("Mc").run().assets("logo");
+use speedrunapi::{Client, Direction, GameData, ModeratorRole};
+use speedrunapi::game_data::{GameAsset, GameOrder, RecordScope};
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+#[tokio::test]
+async fn print(){
+    let client = Client::new();
+    let result = GameData::new("Mc").send(&client).await.unwrap().assets(GameAsset::Logo);
     //assert_eq!(result, Vec::<String>::new());
     println!("{:?}", result);
 }
+
+#[tokio::test]
+async fn optional(){
+    let (server, client) = common::stand_in().await;
+    common::serve(&server, "/games/mc", "game").await;
+    let result = GameData::new("mc").send(&client).await.unwrap();
+    assert_eq!(result.japanese_name(), None);
+    assert_eq!(result.release_date().as_deref(), Some("2011-11-18"));
+    assert_eq!(result.created().as_deref(), Some("2015-01-29 23:41:21"));
+    assert_eq!(result.discord().as_deref(), Some("https://discord.gg/jmdFn3C"));
+    assert_eq!(result.boosts(), Some(0));
+    assert_eq!(result.regions(), Some(Vec::new()));
+    assert_eq!(result.platforms().unwrap(), ["8gej2n93"]);
+    assert_eq!(result.assets(GameAsset::Trophy4th), None);
+    assert_eq!(result.moderators(), [
+        ("kj9p3v8m".to_string(), Some(ModeratorRole::Moderator)),
+        ("zx7gd1yx".to_string(), Some(ModeratorRole::SuperModerator)),
+    ]);
+}
+
+#[tokio::test]
+async fn missing_fields(){
+    let (server, client) = common::stand_in().await;
+    common::serve(&server, "/games/mc", "game_null").await;
+    let result = GameData::new("mc").send(&client).await.unwrap();
+    assert_eq!(result.created(), None);
+    assert_eq!(result.discord(), None);
+    // The empty Discord is sent back the way speedrun.com sent it
+    assert_eq!(serde_json::to_value(&result).unwrap()["discord"], "");
+}
+
+#[tokio::test]
+async fn bad_date(){
+    // A date in another format is returned as it is instead of panicking
+    let (server, client) = common::stand_in().await;
+    let body = common::fixture("game").replace("2015-01-29T23:41:21Z", "29/01/2015");
+    Mock::given(method("GET"))
+        .and(path("/games/mc"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "application/json"))
+        .mount(&server)
+        .await;
+    let result = GameData::new("mc").send(&client).await.unwrap();
+    assert_eq!(result.created().as_deref(), Some("29/01/2015"));
+}
+
+#[tokio::test]
+async fn serialize(){
+    let (server, client) = common::stand_in().await;
+    common::serve(&server, "/games/mc", "game").await;
+    let result = GameData::new("mc").send(&client).await.unwrap();
+    let json = serde_json::to_value(&result).unwrap();
+    let fixture: serde_json::Value = serde_json::from_str(&common::fixture("game")).unwrap();
+    assert_eq!(json, fixture["data"]);
+    let game: speedrunapi::Game = serde_json::from_value(json).unwrap();
+    assert_eq!(game, result);
+}
+
+#[tokio::test]
+async fn categories(){
+    let (server, client) = common::stand_in().await;
+    Mock::given(method("GET"))
+        .and(path("/games/mc/categories"))
+        .and(query_param("miscellaneous", "no"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(common::fixture("categories"), "application/json"))
+        .mount(&server)
+        .await;
+    let result = GameData::new("mc").categories().miscellaneous(false).send(&client).await.unwrap();
+    assert_eq!(result.len(), 2);
+    assert_eq!(result[0].name, "Any% Glitchless");
+    assert_eq!(result[1].rules, None);
+}
+
+#[tokio::test]
+async fn sub_resources(){
+    let (server, client) = common::stand_in().await;
+    common::serve(&server, "/games/mc/levels", "levels").await;
+    common::serve(&server, "/games/mc/variables", "variables").await;
+    common::serve(&server, "/games/mc/derived-games", "games").await;
+    Mock::given(method("GET"))
+        .and(path("/games/mc/records"))
+        .and(query_param("top", "1"))
+        .and(query_param("scope", "full-game"))
+        .and(query_param("skip-empty", "yes"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(common::fixture("records"), "application/json"))
+        .mount(&server)
+        .await;
+    let game = GameData::new("mc");
+    assert_eq!(game.levels().send(&client).await.unwrap()[0].name, "Shrub Forest");
+    assert_eq!(game.variables().send(&client).await.unwrap()[1].category.as_deref(), Some("mkeyl926"));
+    assert_eq!(game.derived_games().send(&client).await.unwrap()[0].abbreviation, "mc");
+    let records = game.records().top(1).scope(RecordScope::FullGame).skip_empty(true).send(&client).await.unwrap();
+    assert_eq!(records[0].category, "mkeyl926");
+}
+
+#[tokio::test]
+async fn boards(){
+    let (server, client) = common::stand_in().await;
+    common::serve(&server, "/games/mc/categories", "board_categories").await;
+    common::serve(&server, "/games/mc/levels", "levels").await;
+    common::serve(&server, "/games/mc/variables", "variables").await;
+    let result = GameData::new("mc").boards().send(&client).await.unwrap();
+    let names: Vec<&str> = result.iter().map(|board| board.name.as_str()).collect();
+    assert_eq!(names, [
+        "Any% Glitchless - 1.16+",
+        "Any% Glitchless - 1.9-1.15",
+        "Co-op - 1.16+",
+        "Co-op - 1.9-1.15",
+        "Shrub Forest: Any%",
+    ]);
+    assert_eq!(result[0].values["jlzkwql2"], "mln68v0q");
+    assert_eq!(result[4].level.as_deref(), Some("495ggmwp"));
+    assert!(result[4].values.is_empty());
+}
+
+#[tokio::test]
+async fn search(){
+    let (server, client) = common::stand_in().await;
+    Mock::given(method("GET"))
+        .and(path("/games"))
+        .and(query_param("name", "minecraft"))
+        .and(query_param("genre", "q4n60ln9"))
+        .and(query_param("moderator", "zx7gd1yx"))
+        .and(query_param("orderby", "similarity"))
+        .and(query_param("direction", "desc"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(common::fixture("games"), "application/json"))
+        .mount(&server)
+        .await;
+    let result = GameData::list()
+        .name("minecraft")
+        .genre("q4n60ln9")
+        .moderator("zx7gd1yx")
+        .order(GameOrder::Similarity, Direction::Descending)
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(result[0].abbreviation, "mc");
+}
+
+#[tokio::test]
+async fn bulk(){
+    let (server, client) = common::stand_in().await;
+    let body = serde_json::json!({ "data": [{
+        "id": "j1npme6p",
+        "names": { "international": "Minecraft: Java Edition", "japanese": null, "twitch": "Minecraft" },
+        "abbreviation": "mc",
+        "weblink": "https://www.speedrun.com/mc"
+    }] });
+    Mock::given(method("GET"))
+        .and(path("/games"))
+        .and(query_param("_bulk", "yes"))
+        .and(query_param("max", "1000"))
+        .and(query_param("romhack", "yes"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(&server)
+        .await;
+    let result = GameData::list().romhack(true).bulk().max(1000).send(&client).await.unwrap();
+    assert_eq!(result[0].names.international, "Minecraft: Java Edition");
+}
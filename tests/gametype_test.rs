@@ -1,19 +1,22 @@
-use speedrunapi::GameTypeData;
+use speedrunapi::{Client, GameTypeData};
 
-#[test]
-fn print(){
-    let result = GameTypeData::new("Fangame").run();
+#[tokio::test]
+async fn print(){
+    let client = Client::new();
+    let result = GameTypeData::new("Fangame").send(&client).await.unwrap();
     println!("{:?}", result)
 }
 
-#[test]
-fn name(){
-    let result = GameTypeData::new("d91jd1ex").run();
+#[tokio::test]
+async fn name(){
+    let client = Client::new();
+    let result = GameTypeData::new("d91jd1ex").send(&client).await.unwrap();
     assert_eq!(result.name(), "Fangame");
 }
 
-#[test]
-fn id(){
-    let result = GameTypeData::new("Fangame").run();
+#[tokio::test]
+async fn id(){
+    let client = Client::new();
+    let result = GameTypeData::new("Fangame").send(&client).await.unwrap();
     assert_eq!(result.id(), "d91jd1ex");
 }
\ No newline at end of file
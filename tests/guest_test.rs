@@ -1,9 +1,23 @@
-//use speedrunapi::guest_data;
+mod common;
+
+use speedrunapi::GuestData;
+
+//use speedrunapi::{Client, GuestData};
 
 // This code is commented out becuase it causes an error, the error is intantinal!
 
-//#[test]
-//fn data(){
-   // let result = guest_data("Alex");
+//#[tokio::test]
+//async fn data(){
+   // let client = Client::new();
+   // let result = GuestData::new("Alex").send(&client).await;
    // println!("{:#?}", result);
-//}
\ No newline at end of file
+//}
+
+#[tokio::test]
+async fn stand_in(){
+    let (server, client) = common::stand_in().await;
+    common::serve(&server, "/guests/Alex", "guest").await;
+    let result = GuestData::new("Alex").send(&client).await.unwrap();
+    assert_eq!(result.name, "Alex");
+    assert_eq!(result.links[1].rel, "runs");
+}
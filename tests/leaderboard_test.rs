@@ -0,0 +1,42 @@
+mod common;
+
+use speedrunapi::leaderboard_data::Timing;
+use speedrunapi::LeaderboardData;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+#[tokio::test]
+async fn category(){
+    let (server, client) = common::stand_in().await;
+    Mock::given(method("GET"))
+        .and(path("/leaderboards/j1npme6p/category/mkeyl926"))
+        .and(query_param("top", "1"))
+        .and(query_param("var-jlzkwql2", "mln68v0q"))
+        .and(query_param("timing", "ingame"))
+        .and(query_param("video-only", "no"))
+        .and(query_param("emulators", "yes"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(common::fixture("leaderboard"), "application/json"))
+        .mount(&server)
+        .await;
+    let result = LeaderboardData::new("j1npme6p", "mkeyl926")
+        .top(1)
+        .variable("jlzkwql2", "mln68v0q")
+        .timing(Timing::Ingame)
+        .video_only(false)
+        .emulators(true)
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(result.timing.as_deref(), Some("ingame"));
+    assert_eq!(result.runs.len(), 2);
+    assert!(result.runs.iter().all(|entry| entry.place == 1));
+    assert_eq!(result.runs[1].run.id, "zpq8w1wm");
+}
+
+#[tokio::test]
+async fn level(){
+    let (server, client) = common::stand_in().await;
+    common::serve(&server, "/leaderboards/j1npme6p/level/495ggmwp/mkeyl926", "leaderboard").await;
+    let result = LeaderboardData::new("j1npme6p", "mkeyl926").level("495ggmwp").send(&client).await;
+    assert!(result.is_ok());
+}
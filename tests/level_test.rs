@@ -1,32 +1,76 @@
-use speedrunapi::LevelData;
+mod common;
 
-#[test]
-fn print(){
-    let result = LevelData::new("495ggmwp").run();
+use speedrunapi::{Client, LevelData};
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+#[tokio::test]
+async fn print(){
+    let client = Client::new();
+    let result = LevelData::new("495ggmwp").send(&client).await.unwrap();
     println!("{:?}", result);
 }
 
-#[test]
-fn name(){
-    let result = LevelData::new("495ggmwp").run();
+#[tokio::test]
+async fn name(){
+    let client = Client::new();
+    let result = LevelData::new("495ggmwp").send(&client).await.unwrap();
     assert_eq!(result.name(), "Shrub Forest");
 }
 
-#[test]
-fn id(){
-    let result = LevelData::new("495ggmwp").run();
+#[tokio::test]
+async fn id(){
+    let client = Client::new();
+    let result = LevelData::new("495ggmwp").send(&client).await.unwrap();
     assert_eq!(result.id(), "495ggmwp");
 }
 
-#[test]
-fn weblink(){
-    let result = LevelData::new("495ggmwp").run();
+#[tokio::test]
+async fn weblink(){
+    let client = Client::new();
+    let result = LevelData::new("495ggmwp").send(&client).await.unwrap();
     assert_eq!(result.weblink(), "https://www.speedrun.com/pokemon_rumble_world/Shrub_Forest");
 }
 
-#[test]
-fn links(){
-    let result = LevelData::new("495ggmwp").run();
+#[tokio::test]
+async fn links(){
+    let client = Client::new();
+    let result = LevelData::new("495ggmwp").send(&client).await.unwrap();
     println!("{:1} {:2}", result.links()[0].0, result.links()[0].1);
     println!("{:?}", result.links());
-}
\ No newline at end of file
+}
+
+#[tokio::test]
+async fn rules(){
+    let (server, client) = common::stand_in().await;
+    common::serve(&server, "/levels/495ggmwp", "level").await;
+    let result = LevelData::new("495ggmwp").send(&client).await.unwrap();
+    assert_eq!(result.rules().as_deref(), Some("Normal Mode: Just go as fast as you can through the level. hard Mode: You must kill every Pokemon [Except the infinite re-spawning ones at the boss battle] Your time is the In game time."));
+}
+
+#[tokio::test]
+async fn no_rules(){
+    let (server, client) = common::stand_in().await;
+    common::serve(&server, "/levels/495ggmwp", "level_null").await;
+    let result = LevelData::new("495ggmwp").send(&client).await.unwrap();
+    assert_eq!(result.rules(), None);
+}
+
+#[tokio::test]
+async fn sub_resources(){
+    let (server, client) = common::stand_in().await;
+    common::serve(&server, "/levels/495ggmwp/categories", "categories").await;
+    common::serve(&server, "/levels/495ggmwp/variables", "variables").await;
+    Mock::given(method("GET"))
+        .and(path("/levels/495ggmwp/records"))
+        .and(query_param("top", "3"))
+        .and(query_param("skip-empty", "no"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(common::fixture("records"), "application/json"))
+        .mount(&server)
+        .await;
+    let level = LevelData::new("495ggmwp");
+    assert_eq!(level.categories().send(&client).await.unwrap()[0].id, "mkeyl926");
+    assert_eq!(level.variables().send(&client).await.unwrap()[0].name, "Version");
+    let records = level.records().top(3).skip_empty(false).send(&client).await.unwrap();
+    assert_eq!(records.len(), 1);
+}
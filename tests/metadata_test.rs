@@ -0,0 +1,46 @@
+mod common;
+
+use speedrunapi::{Direction, GameTypeData, GenreData, PlatformData, PublisherData};
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+#[tokio::test]
+async fn lookup(){
+    let (server, client) = common::stand_in().await;
+    common::serve(&server, "/platforms/8gej2n93", "platform").await;
+    let result = PlatformData::new("8gej2n93").send(&client).await.unwrap();
+    assert_eq!(result.name, "PC");
+    assert_eq!(result.released, 1981);
+}
+
+#[tokio::test]
+async fn list(){
+    let (server, client) = common::stand_in().await;
+    Mock::given(method("GET"))
+        .and(path("/genres"))
+        .and(query_param("max", "2"))
+        .and(query_param("offset", "20"))
+        .and(query_param("orderby", "name"))
+        .and(query_param("direction", "asc"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(common::fixture("genres"), "application/json"))
+        .mount(&server)
+        .await;
+    let result = GenreData::list().max(2).offset(20).order(Direction::Ascending).send(&client).await.unwrap();
+    let names: Vec<&str> = result.iter().map(|genre| genre.name.as_str()).collect();
+    assert_eq!(names, ["Action", "Sandbox"]);
+}
+
+#[tokio::test]
+async fn gametypes(){
+    let (server, client) = common::stand_in().await;
+    common::serve(&server, "/gametypes", "gametypes").await;
+    let result = GameTypeData::list().send(&client).await.unwrap();
+    assert_eq!(result[0].name(), "Fangame");
+}
+
+#[tokio::test]
+async fn missing(){
+    let (_server, client) = common::stand_in().await;
+    let result = PublisherData::new("missing").send(&client).await;
+    assert!(matches!(result, Err(speedrunapi::Error::NotFound{ .. })));
+}
@@ -0,0 +1,84 @@
+mod common;
+
+use futures_util::StreamExt;
+use speedrunapi::{Error, RunData};
+use wiremock::matchers::{method, path, query_param, query_param_is_missing};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Makes a page of runs from the fixture, with the ids given and a link to the next page if there is one
+fn page(ids: &[&str], offset: u32, next: Option<String>) -> serde_json::Value{
+    let fixture: serde_json::Value = serde_json::from_str(&common::fixture("runs")).unwrap();
+    let runs: Vec<serde_json::Value> = ids.iter().map(|id| {
+        let mut run = fixture["data"][0].clone();
+        run["id"] = (*id).into();
+        run
+    }).collect();
+    let links: Vec<serde_json::Value> = next.into_iter().map(|uri| serde_json::json!({ "rel": "next", "uri": uri })).collect();
+    serde_json::json!({
+        "data": runs,
+        "pagination": { "offset": offset, "max": 2, "size": ids.len(), "links": links }
+    })
+}
+
+/// Serves three pages of runs, the second page links to speedrun.com instead of the stand-in
+async fn pages(server: &MockServer, last: ResponseTemplate, last_calls: u64){
+    Mock::given(method("GET"))
+        .and(path("/runs"))
+        .and(query_param_is_missing("offset"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page(&["a", "b"], 0, Some(format!("{}/runs?game=mc&max=2&offset=2", server.uri())))))
+        .expect(1)
+        .mount(server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/runs"))
+        .and(query_param("offset", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page(&["c", "d"], 2, Some("https://www.speedrun.com/api/v1/runs?game=mc&max=2&offset=4".to_string()))))
+        .expect(1)
+        .mount(server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/runs"))
+        .and(query_param("offset", "4"))
+        .respond_with(last)
+        .expect(last_calls)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn every_page(){
+    let (server, client) = common::stand_in().await;
+    pages(&server, ResponseTemplate::new(200).set_body_json(page(&["e"], 4, None)), 1).await;
+    let runs = RunData::list().game("mc").max(2).stream(&client).collect_all().await.unwrap();
+    let ids: Vec<&str> = runs.iter().map(|run| run.id.as_str()).collect();
+    assert_eq!(ids, ["a", "b", "c", "d", "e"]);
+}
+
+#[tokio::test]
+async fn limit(){
+    let (server, client) = common::stand_in().await;
+    pages(&server, ResponseTemplate::new(200).set_body_json(page(&["e"], 4, None)), 0).await;
+    let runs: Vec<_> = RunData::list().game("mc").max(2).stream(&client).limit(3).collect().await;
+    assert_eq!(runs.len(), 3);
+    assert_eq!(runs[2].as_ref().unwrap().id, "c");
+}
+
+#[tokio::test]
+async fn error(){
+    let (server, client) = common::stand_in().await;
+    pages(&server, ResponseTemplate::new(503), 1).await;
+    let mut runs = RunData::list().game("mc").max(2).stream(&client);
+    for _ in 0..4{
+        assert!(runs.next().await.unwrap().is_ok());
+    }
+    assert!(matches!(runs.next().await, Some(Err(Error::Server{ .. }))));
+    assert!(runs.next().await.is_none());
+}
+
+#[tokio::test]
+async fn not_paginated(){
+    let (server, client) = common::stand_in().await;
+    common::serve(&server, "/games/mc/derived-games", "games").await;
+    let games = speedrunapi::GameData::new("mc").derived_games().stream(&client).collect_all().await.unwrap();
+    assert_eq!(games.len(), 1);
+}
@@ -0,0 +1,73 @@
+mod common;
+
+use std::time::{Duration, Instant};
+use speedrunapi::{Error, GameTypeData};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Answers the first request with the rate limit status and the delay, if there is one
+async fn throttle(server: &MockServer, retry_after: Option<&str>){
+    let mut response = ResponseTemplate::new(420).set_body_string(r#"{"status": 420, "message": "Too many requests"}"#);
+    if let Some(retry_after) = retry_after{
+        response = response.insert_header("Retry-After", retry_after);
+    }
+    Mock::given(method("GET"))
+        .and(path("/gametypes/Fangame"))
+        .respond_with(response)
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn shared(){
+    let (server, client) = common::stand_in_with(|builder| builder.rate_limit(2, Duration::from_millis(400))).await;
+    common::serve(&server, "/gametypes/Fangame", "gametype").await;
+    let start = Instant::now();
+    // Two requests go at once and the other two wait for tokens, even though every task has its own clone
+    let tasks: Vec<_> = (0..4).map(|_| {
+        let client = client.clone();
+        tokio::spawn(async move { GameTypeData::new("Fangame").send(&client).await })
+    }).collect();
+    for task in tasks{
+        assert!(task.await.unwrap().is_ok());
+    }
+    assert!(start.elapsed() >= Duration::from_millis(380), "took {:?}", start.elapsed());
+}
+
+#[tokio::test]
+async fn throttled(){
+    let (server, client) = common::stand_in_with(|builder| builder.rate_limit(100, Duration::from_secs(60))).await;
+    common::serve(&server, "/gametypes/Fangame", "gametype").await;
+    throttle(&server, Some("1")).await;
+    match GameTypeData::new("Fangame").send(&client).await{
+        Err(Error::RateLimited{ retry_after }) => assert_eq!(retry_after, Some(Duration::from_secs(1))),
+        result => panic!("Expected RateLimited, got: {:?}", result),
+    }
+    // The next request waits until the delay is over
+    let start = Instant::now();
+    assert!(GameTypeData::new("Fangame").send(&client).await.is_ok());
+    assert!(start.elapsed() >= Duration::from_millis(950), "took {:?}", start.elapsed());
+}
+
+#[tokio::test]
+async fn throttled_without_delay(){
+    let (server, client) = common::stand_in_with(|builder| builder.rate_limit(100, Duration::from_millis(500))).await;
+    common::serve(&server, "/gametypes/Fangame", "gametype").await;
+    throttle(&server, None).await;
+    match GameTypeData::new("Fangame").send(&client).await{
+        Err(Error::RateLimited{ retry_after }) => assert_eq!(retry_after, Some(Duration::from_millis(500))),
+        result => panic!("Expected RateLimited, got: {:?}", result),
+    }
+}
+
+#[tokio::test]
+async fn unlimited(){
+    let (server, client) = common::stand_in_with(|builder| builder.no_rate_limit()).await;
+    throttle(&server, None).await;
+    match GameTypeData::new("Fangame").send(&client).await{
+        Err(Error::RateLimited{ retry_after }) => assert_eq!(retry_after, None),
+        result => panic!("Expected RateLimited, got: {:?}", result),
+    }
+}
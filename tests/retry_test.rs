@@ -0,0 +1,102 @@
+mod common;
+
+use std::time::{Duration, Instant};
+use speedrunapi::{Client, Error, GameTypeData, RetryPolicy};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Returns a policy with short delays
+fn short() -> RetryPolicy{
+    RetryPolicy::new().base_delay(Duration::from_millis(10))
+}
+
+/// Answers the gametype with the status this many times, before anything mounted after it
+async fn fail(server: &MockServer, response: ResponseTemplate, times: u64){
+    Mock::given(method("GET"))
+        .and(path("/gametypes/Fangame"))
+        .respond_with(response)
+        .up_to_n_times(times)
+        .expect(times)
+        .with_priority(1)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn recovers(){
+    let (server, client) = common::stand_in_with(|builder| builder.retry(short())).await;
+    fail(&server, ResponseTemplate::new(503), 2).await;
+    common::serve(&server, "/gametypes/Fangame", "gametype").await;
+    let result = GameTypeData::new("Fangame").send(&client).await.unwrap();
+    assert_eq!(result.id(), "d91jd1ex");
+}
+
+#[tokio::test]
+async fn gives_up(){
+    let (server, client) = common::stand_in_with(|builder| builder.retry(short().max_attempts(4))).await;
+    fail(&server, ResponseTemplate::new(502), 4).await;
+    match GameTypeData::new("Fangame").send(&client).await{
+        Err(Error::Server{ status, .. }) => assert_eq!(status.as_u16(), 502),
+        result => panic!("Expected Server, got: {:?}", result),
+    }
+}
+
+#[tokio::test]
+async fn not_retried(){
+    let (server, client) = common::stand_in_with(|builder| builder.retry(short())).await;
+    fail(&server, ResponseTemplate::new(404), 1).await;
+    assert!(matches!(GameTypeData::new("Fangame").send(&client).await, Err(Error::NotFound{ .. })));
+
+    let (server, client) = common::stand_in_with(|builder| builder.retry(short().statuses(&[500]))).await;
+    fail(&server, ResponseTemplate::new(503), 1).await;
+    assert!(matches!(GameTypeData::new("Fangame").send(&client).await, Err(Error::Server{ .. })));
+}
+
+#[tokio::test]
+async fn retry_after(){
+    let (server, client) = common::stand_in_with(|builder| builder.retry(short())).await;
+    fail(&server, ResponseTemplate::new(420).insert_header("Retry-After", "1"), 1).await;
+    common::serve(&server, "/gametypes/Fangame", "gametype").await;
+    let start = Instant::now();
+    assert!(GameTypeData::new("Fangame").send(&client).await.is_ok());
+    assert!(start.elapsed() >= Duration::from_millis(950), "took {:?}", start.elapsed());
+}
+
+#[tokio::test]
+async fn retry_after_date(){
+    let (server, client) = common::stand_in_with(|builder| builder.retry(short())).await;
+    let date = (chrono::Utc::now() + chrono::Duration::seconds(2)).format("%a, %d %b %Y %H:%M:%S GMT").to_string();
+    fail(&server, ResponseTemplate::new(503).insert_header("Retry-After", date.as_str()), 1).await;
+    common::serve(&server, "/gametypes/Fangame", "gametype").await;
+    let start = Instant::now();
+    assert!(GameTypeData::new("Fangame").send(&client).await.is_ok());
+    // The date is only precise to the second
+    assert!(start.elapsed() >= Duration::from_millis(950), "took {:?}", start.elapsed());
+}
+
+#[tokio::test]
+async fn retry_after_too_long(){
+    let (server, client) = common::stand_in_with(|builder| builder.retry(short().max_delay(Duration::from_secs(5)))).await;
+    fail(&server, ResponseTemplate::new(429).insert_header("Retry-After", "60"), 1).await;
+    // The error is returned at once with the delay, instead of retrying early
+    let start = Instant::now();
+    match GameTypeData::new("Fangame").send(&client).await{
+        Err(Error::RateLimited{ retry_after }) => assert_eq!(retry_after, Some(Duration::from_secs(60))),
+        result => panic!("Expected RateLimited, got: {:?}", result),
+    }
+    assert!(start.elapsed() < Duration::from_secs(1), "took {:?}", start.elapsed());
+}
+
+#[tokio::test]
+async fn connection(){
+    // Nothing listens on the port once the listener is dropped
+    let port = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+    let client = Client::builder()
+        .base_url(&format!("http://127.0.0.1:{}", port))
+        .retry(RetryPolicy::new().base_delay(Duration::from_millis(100)).jitter(false))
+        .build()
+        .unwrap();
+    let start = Instant::now();
+    assert!(matches!(GameTypeData::new("Fangame").send(&client).await, Err(Error::Transport(_))));
+    assert!(start.elapsed() >= Duration::from_millis(300), "took {:?}", start.elapsed());
+}
@@ -0,0 +1,50 @@
+mod common;
+
+use std::time::Duration;
+
+use speedrunapi::run_data::{RunOrder, Status};
+use speedrunapi::types::{Player, RunStatus};
+use speedrunapi::{Direction, RunData};
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+#[tokio::test]
+async fn lookup(){
+    let (server, client) = common::stand_in().await;
+    common::serve(&server, "/runs/90y6pm7e", "run").await;
+    let result = RunData::new("90y6pm7e").send(&client).await.unwrap();
+    assert_eq!(result.time(), Duration::from_secs_f64(4664.5));
+    assert_eq!(result.video_links(), ["https://www.youtube.com/watch?v=e9p0kCsNP9A"]);
+    assert!(result.is_verified());
+    assert_eq!(result.values["ylqm6vlg"], "21d4zvp1");
+    assert!(matches!(&result.players[1], Player::Guest{ name, .. } if name == "Alex"));
+}
+
+#[tokio::test]
+async fn filters(){
+    let (server, client) = common::stand_in().await;
+    Mock::given(method("GET"))
+        .and(path("/runs"))
+        .and(query_param("game", "j1npme6p"))
+        .and(query_param("guest", "Alex Smith"))
+        .and(query_param("emulated", "yes"))
+        .and(query_param("status", "rejected"))
+        .and(query_param("orderby", "verify-date"))
+        .and(query_param("direction", "desc"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(common::fixture("runs"), "application/json"))
+        .mount(&server)
+        .await;
+    let result = RunData::list()
+        .game("j1npme6p")
+        .guest("Alex Smith")
+        .emulated(true)
+        .status(Status::Rejected)
+        .order(RunOrder::VerifyDate, Direction::Descending)
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(result.len(), 2);
+    assert_eq!(result[0].status, RunStatus::Rejected{ examiner: Some("zx7gd1yx".to_string()), reason: Some("No video".to_string()) });
+    assert_eq!(result[1].status, RunStatus::New);
+    assert!(result[1].video_links().is_empty());
+}
@@ -0,0 +1,57 @@
+mod common;
+
+use speedrunapi::game_data::GameOrder;
+use speedrunapi::series_data::SeriesOrder;
+use speedrunapi::{Direction, ModeratorRole, SeriesData};
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+#[tokio::test]
+async fn lookup(){
+    let (server, client) = common::stand_in().await;
+    common::serve(&server, "/series/mario", "series").await;
+    let result = SeriesData::new("mario").send(&client).await.unwrap();
+    assert_eq!(result.names.international, "Mario");
+    assert_eq!(result.abbreviation, "mario");
+    assert_eq!(result.moderators["zx7gd1yx"], Some(ModeratorRole::SuperModerator));
+}
+
+#[tokio::test]
+async fn list(){
+    let (server, client) = common::stand_in().await;
+    Mock::given(method("GET"))
+        .and(path("/series"))
+        .and(query_param("name", "mario"))
+        .and(query_param("orderby", "created"))
+        .and(query_param("direction", "desc"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(common::fixture("series_list"), "application/json"))
+        .mount(&server)
+        .await;
+    let result = SeriesData::list().name("mario").order(SeriesOrder::Created, Direction::Descending).send(&client).await.unwrap();
+    assert_eq!(result[0].id, "rv7emz49");
+}
+
+#[tokio::test]
+async fn games(){
+    let (server, client) = common::stand_in().await;
+    Mock::given(method("GET"))
+        .and(path("/series/mario/games"))
+        .and(query_param("platform", "8gej2n93"))
+        .and(query_param("released", "2011"))
+        .and(query_param("romhack", "no"))
+        .and(query_param("orderby", "name.int"))
+        .and(query_param("direction", "asc"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(common::fixture("games"), "application/json"))
+        .mount(&server)
+        .await;
+    let result = SeriesData::new("mario")
+        .games()
+        .platform("8gej2n93")
+        .released(2011)
+        .romhack(false)
+        .order(GameOrder::Name, Direction::Ascending)
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(result[0].name(), "Minecraft: Java Edition");
+}
@@ -1,8 +1,64 @@
+mod common;
+
 use speedrunapi::{tl_time};
+use speedrunapi::GameData;
+use speedrunapi::translate::Translator;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[test]
 fn time(){
     let result = tl_time!("2014-10-02T12:34:23Z");
     assert_eq!(result, "2014-10-02 12:34:23");
     println!("{}", result);
-}
\ No newline at end of file
+}
+
+/// Answers the path with a resource that only has an id and a name, exactly once
+async fn named(server: &MockServer, route: &str, id: &str, name: &str){
+    let body = serde_json::json!({ "data": { "id": id, "name": name, "released": 1981, "links": [] } });
+    Mock::given(method("GET"))
+        .and(path(route))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+/// Answers the path with the user fixture renamed, exactly once
+async fn user(server: &MockServer, id: &str, name: &str){
+    let mut body: serde_json::Value = serde_json::from_str(&common::fixture("user")).unwrap();
+    body["data"]["id"] = id.into();
+    body["data"]["names"]["international"] = name.into();
+    Mock::given(method("GET"))
+        .and(path(format!("/users/{}", id)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn resolve(){
+    let (server, client) = common::stand_in().await;
+    common::serve(&server, "/games/mc", "game").await;
+    named(&server, "/platforms/8gej2n93", "8gej2n93", "PC").await;
+    named(&server, "/genres/q4n60ln9", "q4n60ln9", "Action").await;
+    named(&server, "/genres/jp230326", "jp230326", "Sandbox").await;
+    named(&server, "/developers/k62d97ex", "k62d97ex", "Mojang Studios").await;
+    user(&server, "zx7gd1yx", "Alpha").await;
+    user(&server, "kj9p3v8m", "Beta").await;
+
+    let translator = Translator::new(&client);
+    let game = GameData::new("mc").send(&client).await.unwrap();
+    let resolved = game.resolve(&translator).await.unwrap();
+    assert_eq!(resolved.to_string(), "Minecraft: Java Edition — PC — Action, Sandbox");
+    assert_eq!(resolved.developers, ["Mojang Studios"]);
+    assert!(resolved.regions.is_empty());
+    assert_eq!(resolved.moderators.len(), 2);
+    assert!(resolved.moderators.contains(&"Alpha".to_string()));
+
+    // The second time every name is remembered, the mocks fail the test if they are asked again
+    let again = game.resolve(&translator.clone()).await.unwrap();
+    assert_eq!(again, resolved);
+    assert_eq!(translator.platform("8gej2n93").await.unwrap(), "PC");
+}
@@ -1,7 +1,100 @@
-use speedrunapi::UserData;
+mod common;
 
-#[test]
-fn print(){
-    let result = UserData::new("fishin_rod").run();
+use speedrunapi::{Client, Direction, Error, UserData};
+use speedrunapi::user_data::{NameType, Service, UserOrder};
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+#[tokio::test]
+async fn print(){
+    let client = Client::new();
+    let result = UserData::new("fishin_rod").send(&client).await.unwrap();
     println!("{:?}", result);
 }
+
+#[tokio::test]
+async fn stand_in(){
+    let (server, client) = common::stand_in().await;
+    common::serve(&server, "/users/fishin_rod", "user").await;
+    let result = UserData::new("fishin_rod").send(&client).await.unwrap();
+    assert_eq!(result.id(), "jonryvl8");
+    assert_eq!(result.country(NameType::Code).as_deref(), Some("us"));
+    assert_eq!(result.region(NameType::Japanese), None);
+    assert_eq!(result.pronouns().as_deref(), Some("He/Him"));
+    assert_eq!(result.signup().as_deref(), Some("2022-12-20 01:16:42"));
+    assert_eq!(result.links(Service::Twitch), None);
+}
+
+#[tokio::test]
+async fn no_location(){
+    let (server, client) = common::stand_in().await;
+    common::serve(&server, "/users/fishin_rod", "user_null").await;
+    let result = UserData::new("fishin_rod").send(&client).await.unwrap();
+    assert_eq!(result.location, None);
+    assert_eq!(result.country(NameType::Code), None);
+    assert_eq!(result.region(NameType::Code), None);
+}
+
+#[tokio::test]
+async fn bad_date(){
+    // A date in another format is returned as it is instead of panicking
+    let (server, client) = common::stand_in().await;
+    let body = common::fixture("user").replace("2022-12-20T01:16:42Z", "");
+    Mock::given(method("GET"))
+        .and(path("/users/fishin_rod"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "application/json"))
+        .mount(&server)
+        .await;
+    let result = UserData::new("fishin_rod").send(&client).await.unwrap();
+    assert_eq!(result.signup().as_deref(), Some(""));
+}
+
+#[tokio::test]
+async fn missing(){
+    // A user that does not exist is an error, not a panic
+    let (_server, client) = common::stand_in().await;
+    let result = UserData::new("nobody").send(&client).await;
+    assert!(matches!(result, Err(Error::NotFound{ .. })));
+}
+
+#[tokio::test]
+async fn personal_bests(){
+    let (server, client) = common::stand_in().await;
+    Mock::given(method("GET"))
+        .and(path("/users/fishin_rod/personal-bests"))
+        .and(query_param("top", "5"))
+        .and(query_param("game", "mc"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(common::fixture("personal_bests"), "application/json"))
+        .mount(&server)
+        .await;
+    let result = UserData::new("fishin_rod").personal_bests().top(5).game("mc").send(&client).await.unwrap();
+    assert_eq!(result.len(), 2);
+    assert_eq!(result[0].place, 1);
+    assert_eq!(result[0].game(), "j1npme6p");
+    assert_eq!(result[0].level(), None);
+    assert_eq!(result[1].category(), "mkeyl926");
+    assert_eq!(result[1].level(), Some("495ggmwp"));
+}
+
+#[tokio::test]
+async fn search(){
+    let (server, client) = common::stand_in().await;
+    Mock::given(method("GET"))
+        .and(path("/users"))
+        .and(query_param("twitch", "fishin_rod"))
+        .and(query_param("lookup", "fishin_rod"))
+        .and(query_param("orderby", "signup"))
+        .and(query_param("direction", "asc"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(common::fixture("users"), "application/json"))
+        .mount(&server)
+        .await;
+    let result = UserData::list()
+        .twitch("fishin_rod")
+        .lookup("fishin_rod")
+        .order(UserOrder::Signup, Direction::Ascending)
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].name(), "fishin_rod");
+}
@@ -0,0 +1,28 @@
+mod common;
+
+use speedrunapi::types::VariableScope;
+use speedrunapi::VariableData;
+
+#[tokio::test]
+async fn lookup(){
+    let (server, client) = common::stand_in().await;
+    common::serve(&server, "/variables/jlzkwql2", "variable").await;
+    let result = VariableData::new("jlzkwql2").send(&client).await.unwrap();
+    assert_eq!(result.scope, VariableScope::FullGame);
+    assert!(result.mandatory);
+    assert!(!result.user_defined);
+    assert!(result.obsoletes);
+    assert!(result.is_subcategory);
+    assert_eq!(result.values.default.as_deref(), Some("mln68v0q"));
+    assert_eq!(result.label("4qye4731"), Some("1.9-1.15"));
+    assert_eq!(result.label("missing"), None);
+    assert_eq!(result.values.values["mln68v0q"].rules.as_deref(), Some("Versions 1.16 and newer."));
+    assert!(result.applies_to(None));
+    assert!(!result.applies_to(Some("495ggmwp")));
+}
+
+#[test]
+fn scope(){
+    let scope: VariableScope = serde_json::from_str(r#"{"type": "single-level", "level": "495ggmwp"}"#).unwrap();
+    assert_eq!(scope, VariableScope::SingleLevel{ level: "495ggmwp".to_string() });
+}